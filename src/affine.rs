@@ -0,0 +1,256 @@
+use core::{
+    fmt::{Display, Formatter, Result},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::prelude::*;
+
+/// An absolute position.
+///
+/// Subtracting two positions yields the [`Offset`] between them, and offsets can be added to
+/// positions. Adding two positions doesn't compile.
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let spawn = Position::new(Coordinate::new(10, 64, -3));
+/// let player = Position::new(Coordinate::new(12, 60, -3));
+/// let offset = player - spawn;
+///
+/// assert_eq!(offset, Offset::new(Coordinate::new(2, -4, 0)));
+/// assert_eq!(spawn + offset * 2, Position::new(Coordinate::new(14, 56, -3)));
+/// ```
+///
+/// ```compile_fail
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let a = Position::new(Coordinate::new(1, 2, 3));
+/// let b = Position::new(Coordinate::new(4, 5, 6));
+/// let _ = a + b;
+/// ```
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct Position<C = Coordinate>(C);
+
+/// A displacement between two [`Position`]s.
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct Offset<C = Coordinate>(C);
+
+impl<C> Position<C> {
+    /// Treats `coordinate` as an absolute position.
+    pub fn new(coordinate: C) -> Self {
+        Self(coordinate)
+    }
+
+    /// Returns the plain coordinate.
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+
+    /// Returns a reference to the plain coordinate.
+    pub fn as_inner(&self) -> &C {
+        &self.0
+    }
+
+    /// Returns the offset of this position from the origin.
+    pub fn to_offset(self) -> Offset<C> {
+        Offset(self.0)
+    }
+}
+
+impl<C> Offset<C> {
+    /// Treats `coordinate` as a displacement.
+    pub fn new(coordinate: C) -> Self {
+        Self(coordinate)
+    }
+
+    /// Returns the plain coordinate.
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+
+    /// Returns a reference to the plain coordinate.
+    pub fn as_inner(&self) -> &C {
+        &self.0
+    }
+
+    /// Returns the position this offset leads to when starting at the origin.
+    pub fn to_position(self) -> Position<C> {
+        Position(self.0)
+    }
+}
+
+impl<C> From<C> for Position<C> {
+    fn from(coordinate: C) -> Self {
+        Self::new(coordinate)
+    }
+}
+
+impl<C> From<C> for Offset<C> {
+    fn from(coordinate: C) -> Self {
+        Self::new(coordinate)
+    }
+}
+
+impl<C: Display> Display for Position<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<C: Display> Display for Offset<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<C: Sub<Output = C>> Sub for Position<C> {
+    type Output = Offset<C>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Offset(self.0 - rhs.0)
+    }
+}
+
+impl<C: Add<Output = C>> Add<Offset<C>> for Position<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Offset<C>) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<C: AddAssign> AddAssign<Offset<C>> for Position<C> {
+    fn add_assign(&mut self, rhs: Offset<C>) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<C: Sub<Output = C>> Sub<Offset<C>> for Position<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Offset<C>) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<C: SubAssign> SubAssign<Offset<C>> for Position<C> {
+    fn sub_assign(&mut self, rhs: Offset<C>) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<C: Add<Output = C>> Add for Offset<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<C: AddAssign> AddAssign for Offset<C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<C: Sub<Output = C>> Sub for Offset<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<C: SubAssign> SubAssign for Offset<C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<C: Neg<Output = C>> Neg for Offset<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<C: Mul<T, Output = C>, T> Mul<T> for Offset<C> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<C: MulAssign<T>, T> MulAssign<T> for Offset<C> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= rhs;
+    }
+}
+
+impl<C: Div<T, Output = C>, T> Div<T> for Offset<C> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl<C: DivAssign<T>, T> DivAssign<T> for Offset<C> {
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_minus_position() {
+        let a = Position::new(Coordinate::new(5, 0, -5));
+        let b = Position::new(Coordinate::new(1, 2, 3));
+        assert_eq!(a - b, Offset::new(Coordinate::new(4, -2, -8)));
+    }
+
+    #[test]
+    fn position_plus_offset() {
+        let mut position = Position::new(Coordinate::new(1, 2, 3));
+        position += Offset::new(Coordinate::splat(1));
+        assert_eq!(position.as_inner(), &Coordinate::new(2, 3, 4));
+
+        position -= Offset::new(Coordinate::splat(2));
+        assert_eq!(position.into_inner(), Coordinate::new(0, 1, 2));
+    }
+
+    #[test]
+    fn offset_scalar() {
+        let offset = Offset::new(Coordinate::new(1, -2, 3));
+        assert_eq!((offset * 3).into_inner(), Coordinate::new(3, -6, 9));
+        assert_eq!((-offset / 1).into_inner(), Coordinate::new(-1, 2, -3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn offset_overflow() {
+        let _ = Offset::new(Coordinate::MAX) * 2;
+    }
+
+    #[test]
+    fn map_keys() {
+        let position = Position::new(Coordinate::new(1, 2, 3));
+        let positions: alloc::collections::BTreeSet<_> = [position, position].into_iter().collect();
+        assert_eq!(positions.len(), 1);
+        assert!(positions.contains(&position));
+    }
+
+    #[test]
+    fn legacy_conversions() {
+        let position: Position = Coordinate::new(1, 2, 3).into();
+        assert_eq!(position.to_offset().to_position(), position);
+        assert_eq!(position.into_inner(), Coordinate::new(1, 2, 3));
+    }
+}
@@ -0,0 +1,288 @@
+//! Minecraft-style coordinate arguments for console commands.
+//!
+//! Every axis is either absolute (`5`), relative to the origin (`~`, `~-3`), or all three axes
+//! are local to the orientation (`^left ^up ^forward`).
+
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::prelude::*;
+
+/// One of the six axis-aligned directions.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Direction {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+impl Direction {
+    /// Returns the unit `Coordinate` pointing in this direction.
+    pub fn offset(self) -> Coordinate {
+        match self {
+            Self::PositiveX => Coordinate::new(1, 0, 0),
+            Self::NegativeX => Coordinate::new(-1, 0, 0),
+            Self::PositiveY => Coordinate::new(0, 1, 0),
+            Self::NegativeY => Coordinate::new(0, -1, 0),
+            Self::PositiveZ => Coordinate::new(0, 0, 1),
+            Self::NegativeZ => Coordinate::new(0, 0, -1),
+        }
+    }
+}
+
+/// Where a command's caller is facing, used to resolve local (`^`) coordinates.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct Orientation {
+    forward: Direction,
+    up: Direction,
+}
+
+impl Orientation {
+    /// Creates a new `Orientation`.
+    ///
+    /// Returns `None` if `forward` and `up` aren't perpendicular.
+    pub fn new(forward: Direction, up: Direction) -> Option<Self> {
+        (forward.offset().dot(up.offset()) == 0).then_some(Self { forward, up })
+    }
+
+    /// Returns the direction the caller is facing.
+    pub fn forward(&self) -> Direction {
+        self.forward
+    }
+
+    /// Returns the direction above the caller's head.
+    pub fn up(&self) -> Direction {
+        self.up
+    }
+
+    /// Returns the unit `Coordinate` pointing to the caller's left.
+    pub fn left(&self) -> Coordinate {
+        self.up.offset().cross(self.forward.offset())
+    }
+}
+
+/// Facing `+z` with `+y` up, so `+x` is to the left.
+impl Default for Orientation {
+    fn default() -> Self {
+        Self {
+            forward: Direction::PositiveZ,
+            up: Direction::PositiveY,
+        }
+    }
+}
+
+/// A single axis of a [`CommandCoordinate`] in world space.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Component {
+    /// A fixed value, e.g. `5`.
+    Absolute(CoordinateType),
+    /// An offset from the origin, e.g. `~` or `~-3`.
+    Relative(CoordinateType),
+}
+
+/// An unresolved coordinate argument.
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::command::{CommandCoordinate, Direction, Orientation};
+///
+/// let origin = Coordinate::new(10, 64, 10);
+///
+/// let argument: CommandCoordinate = "~ ~1 5".parse().unwrap();
+/// let resolved = argument.resolve(&origin, Orientation::default());
+/// assert_eq!(resolved, Ok(Coordinate::new(10, 65, 5)));
+///
+/// let facing_east = Orientation::new(Direction::PositiveX, Direction::PositiveY).unwrap();
+/// let argument: CommandCoordinate = "^ ^ ^2".parse().unwrap();
+/// assert_eq!(argument.resolve(&origin, facing_east), Ok(Coordinate::new(12, 64, 10)));
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum CommandCoordinate {
+    /// Absolute or `~` relative axes, in the order `x`, `y`, `z`.
+    World([Component; 3]),
+    /// `^` offsets to the left, up and forward.
+    Local([CoordinateType; 3]),
+}
+
+/// Error returned when parsing a [`CommandCoordinate`] fails.
+///
+/// Positions are byte offsets into the parsed string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ParseCommandCoordinateError {
+    #[error("Expected 3 coordinates, found {0}.")]
+    WrongCount(usize),
+    #[error(
+        "Local (^) coordinates can't be mixed with other coordinates, see position {position}."
+    )]
+    MixedLocal { position: usize },
+    #[error("Invalid number for the {axis} axis at position {position}.")]
+    InvalidNumber { axis: Axis, position: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("Resolving the {axis} axis overflows.")]
+pub struct ResolveOverflowError {
+    pub axis: Axis,
+}
+
+impl CommandCoordinate {
+    /// Resolves this argument into an absolute `Coordinate`.
+    ///
+    /// `orientation` is only used by local coordinates.
+    pub fn resolve(
+        &self,
+        origin: &Coordinate,
+        orientation: Orientation,
+    ) -> Result<Coordinate, ResolveOverflowError> {
+        let origin = SignedCoordinate::<i64>::from(*origin);
+        let resolved = match *self {
+            Self::World(components) => {
+                let mut resolved = origin;
+                for (axis, component) in Axis::ALL.into_iter().zip(components) {
+                    resolved[axis] = match component {
+                        Component::Absolute(n) => i64::from(n),
+                        Component::Relative(n) => origin[axis] + i64::from(n),
+                    };
+                }
+                resolved
+            }
+            Self::Local([left, up, forward]) => {
+                let scaled = |direction: Coordinate, n: CoordinateType| {
+                    SignedCoordinate::<i64>::from(direction) * i64::from(n)
+                };
+                origin
+                    + scaled(orientation.left(), left)
+                    + scaled(orientation.up.offset(), up)
+                    + scaled(orientation.forward.offset(), forward)
+            }
+        };
+        resolved
+            .try_into()
+            .map_err(|error: TryFromCoordinateError| ResolveOverflowError { axis: error.axis })
+    }
+}
+
+impl FromStr for CommandCoordinate {
+    type Err = ParseCommandCoordinateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s
+            .split_whitespace()
+            .map(|token| (token.as_ptr() as usize - s.as_ptr() as usize, token));
+        let (Some(x), Some(y), Some(z), None) =
+            (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+        else {
+            let count = s.split_whitespace().count();
+            return Err(ParseCommandCoordinateError::WrongCount(count));
+        };
+        let tokens = [x, y, z];
+
+        let is_local = |(_, token): &(usize, &str)| token.starts_with('^');
+        let local = is_local(&tokens[0]);
+        if let Some(&(position, _)) = tokens.iter().find(|token| is_local(token) != local) {
+            return Err(ParseCommandCoordinateError::MixedLocal { position });
+        }
+
+        let mut components = [Component::Absolute(0); 3];
+        for ((axis, (position, token)), component) in
+            Axis::ALL.into_iter().zip(tokens).zip(&mut components)
+        {
+            let parse = |number: &str| match number {
+                "" => Ok(0),
+                number => number.parse(),
+            };
+            let invalid = |_| ParseCommandCoordinateError::InvalidNumber { axis, position };
+            *component = match token.strip_prefix(['~', '^']) {
+                Some(number) => Component::Relative(parse(number).map_err(invalid)?),
+                None => Component::Absolute(token.parse().map_err(invalid)?),
+            };
+        }
+
+        Ok(match local {
+            true => Self::Local(components.map(|component| match component {
+                Component::Absolute(n) | Component::Relative(n) => n,
+            })),
+            false => Self::World(components),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "~ ~1 -3".parse(),
+            Ok(CommandCoordinate::World([
+                Component::Relative(0),
+                Component::Relative(1),
+                Component::Absolute(-3),
+            ]))
+        );
+        assert_eq!("^1 ^ ^-2".parse(), Ok(CommandCoordinate::Local([1, 0, -2])));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<CommandCoordinate>().unwrap_err();
+        assert_eq!(parse("~ ~"), ParseCommandCoordinateError::WrongCount(2));
+        assert_eq!(
+            parse("^ ~ ^"),
+            ParseCommandCoordinateError::MixedLocal { position: 2 }
+        );
+        assert_eq!(
+            parse("1 2 ^"),
+            ParseCommandCoordinateError::MixedLocal { position: 4 }
+        );
+        assert_eq!(
+            parse("1 ~x 3"),
+            ParseCommandCoordinateError::InvalidNumber {
+                axis: Axis::Y,
+                position: 2
+            }
+        );
+        assert_eq!(
+            parse("1 2 ~~"),
+            ParseCommandCoordinateError::InvalidNumber {
+                axis: Axis::Z,
+                position: 4
+            }
+        );
+    }
+
+    #[test]
+    fn local() {
+        let facing_down = Orientation::new(Direction::NegativeY, Direction::PositiveZ).unwrap();
+        let argument = CommandCoordinate::Local([1, 2, 3]);
+        assert_eq!(
+            argument.resolve(&Coordinate::splat(0), facing_down),
+            Ok(Coordinate::new(1, -3, 2))
+        );
+    }
+
+    #[test]
+    fn orientation() {
+        assert_eq!(
+            Orientation::new(Direction::PositiveX, Direction::NegativeX),
+            None
+        );
+        assert_eq!(Orientation::default().left(), Coordinate::new(1, 0, 0));
+    }
+
+    #[test]
+    fn overflow() {
+        let argument: CommandCoordinate = "~ ~ ~1".parse().unwrap();
+        assert_eq!(
+            argument.resolve(&Coordinate::MAX, Orientation::default()),
+            Err(ResolveOverflowError { axis: Axis::Z })
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::prelude::*;
+
+/// Offsets of all 26 neighbours, ordered by faces, edges and then corners.
+const OFFSETS: [[CoordinateType; 3]; 26] = [
+    [1, 0, 0],
+    [-1, 0, 0],
+    [0, 1, 0],
+    [0, -1, 0],
+    [0, 0, 1],
+    [0, 0, -1],
+    [1, 1, 0],
+    [1, -1, 0],
+    [-1, 1, 0],
+    [-1, -1, 0],
+    [1, 0, 1],
+    [1, 0, -1],
+    [-1, 0, 1],
+    [-1, 0, -1],
+    [0, 1, 1],
+    [0, 1, -1],
+    [0, -1, 1],
+    [0, -1, -1],
+    [1, 1, 1],
+    [1, 1, -1],
+    [1, -1, 1],
+    [1, -1, -1],
+    [-1, 1, 1],
+    [-1, 1, -1],
+    [-1, -1, 1],
+    [-1, -1, -1],
+];
+
+/// Determines which `Coordinate`s count as neighbours of each other.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum Connectivity {
+    /// Neighbours share a face.
+    #[default]
+    Six,
+    /// Neighbours share a face or an edge.
+    Eighteen,
+    /// Neighbours share a face, an edge or a corner.
+    TwentySix,
+}
+
+impl Connectivity {
+    /// Returns the offsets from a `Coordinate` to its neighbours.
+    pub fn offsets(self) -> &'static [[CoordinateType; 3]] {
+        match self {
+            Connectivity::Six => &OFFSETS[..6],
+            Connectivity::Eighteen => &OFFSETS[..18],
+            Connectivity::TwentySix => &OFFSETS,
+        }
+    }
+
+    /// Iterates over the neighbours of `coordinate`.
+    ///
+    /// Neighbours that can't be represented by a `Coordinate` are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// assert_eq!(Connectivity::Six.neighbors(&Coordinate::splat(0)).count(), 6);
+    /// assert_eq!(Connectivity::TwentySix.neighbors(&Coordinate::MAX).count(), 7);
+    /// ```
+    pub fn neighbors(self, coordinate: &Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        self.offsets()
+            .iter()
+            .filter_map(move |&offset| checked_offset(coordinate, offset))
+    }
+}
+
+/// Adds `offset` to `coordinate`, returning `None` on overflow.
+pub(crate) fn checked_offset(
+    coordinate: &Coordinate,
+    offset: [CoordinateType; 3],
+) -> Option<Coordinate> {
+    Some(Coordinate::new(
+        coordinate.x.checked_add(offset[0])?,
+        coordinate.y.checked_add(offset[1])?,
+        coordinate.z.checked_add(offset[2])?,
+    ))
+}
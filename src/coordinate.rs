@@ -1,8 +1,28 @@
-pub use coordinate_trait::CoordinateTrait;
+pub use axis::Axis;
+pub use bool_coordinate::{BoolCoordinate, BoolCoordinate2, CoordinateCompare};
+pub use conversions::TryFromCoordinateError;
+pub use coordinate2::{SignedCoordinate2, UnsignedCoordinate2};
+pub use coordinate_n::CoordinateN;
+pub use coordinate_trait::{Coordinate2Trait, CoordinateField, CoordinateTrait};
+pub use format::{CoordinateFormat, FormatStyle, FormattedCoordinate};
+pub use math::{Coordinate3Math, CoordinateMath};
+pub use parse::ParseCoordinateError;
 pub use signed_coordinate::SignedCoordinate;
 pub use unsigned_coordinate::UnsignedCoordinate;
 
+mod axis;
+mod bool_coordinate;
+mod components;
+mod conversions;
+mod coordinate2;
+mod coordinate_n;
 mod coordinate_trait;
+mod format;
+mod generate;
+mod layout;
+mod math;
+mod ord;
+mod parse;
 mod signed_coordinate;
 mod unsigned_coordinate;
 
@@ -16,3 +36,6 @@ pub type CoordinateType = i32;
 
 /// 3D Coordinate in absolute space.
 pub type Coordinate = SignedCoordinate<CoordinateType>;
+
+/// 2D Coordinate in absolute space, e.g. a column in the `x`/`z` plane.
+pub type Coordinate2 = SignedCoordinate2<CoordinateType>;
@@ -0,0 +1,29 @@
+use core::fmt::{Display, Formatter, Result};
+
+/// One of the three axes of a coordinate.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    /// All axes, in the order `x`, `y`, `z`.
+    pub const ALL: [Self; 3] = [Self::X, Self::Y, Self::Z];
+
+    /// Returns the position of this axis within `[x, y, z]`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Axis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            Self::X => "x",
+            Self::Y => "y",
+            Self::Z => "z",
+        })
+    }
+}
@@ -0,0 +1,279 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+use num::{Signed, Unsigned};
+
+use super::{
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::CoordinateField,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Component-wise comparisons into a mask with one `bool` per axis.
+///
+/// Required by [`CoordinateTrait`](super::CoordinateTrait) with [`BoolCoordinate`] masks and by
+/// [`Coordinate2Trait`](super::Coordinate2Trait) with [`BoolCoordinate2`] masks, so generic code
+/// can do bounds checks like `coordinate.cmpge(min).all() && coordinate.cmple(max).all()`.
+pub trait CoordinateCompare: Sized {
+    /// The mask type, `BoolCoordinate` or `BoolCoordinate2`.
+    type Mask;
+
+    /// Compares each axis for `==`.
+    fn cmpeq(self, other: Self) -> Self::Mask;
+
+    /// Compares each axis for `!=`.
+    fn cmpne(self, other: Self) -> Self::Mask;
+
+    /// Compares each axis for `<`.
+    fn cmplt(self, other: Self) -> Self::Mask;
+
+    /// Compares each axis for `<=`.
+    fn cmple(self, other: Self) -> Self::Mask;
+
+    /// Compares each axis for `>`.
+    fn cmpgt(self, other: Self) -> Self::Mask;
+
+    /// Compares each axis for `>=`.
+    fn cmpge(self, other: Self) -> Self::Mask;
+
+    /// Takes each axis from `if_true` where `mask` is `true`, and from `if_false` otherwise.
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self;
+}
+
+/// Generates the mask type `$name` with the given fields and its operators.
+macro_rules! bool_coordinate {
+    ($(#[$attr:meta])* $name:ident, [$first:ident $(, $field:ident)*]) => {
+        $(#[$attr])*
+        #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+        pub struct $name {
+            pub $first: bool,
+            $(pub $field: bool,)*
+        }
+
+        impl $name {
+            #[doc = concat!("Creates a new `", stringify!($name), "`.")]
+            pub fn new($first: bool, $($field: bool),*) -> Self {
+                Self { $first, $($field),* }
+            }
+
+            #[doc = concat!("Creates a new `", stringify!($name), "` with every axis set to `b`.")]
+            pub fn splat(b: bool) -> Self {
+                Self { $first: b, $($field: b),* }
+            }
+
+            /// Returns `true` if every axis is `true`.
+            pub fn all(self) -> bool {
+                self.$first $(&& self.$field)*
+            }
+
+            /// Returns `true` if at least one axis is `true`.
+            pub fn any(self) -> bool {
+                self.$first $(|| self.$field)*
+            }
+
+            /// Returns `true` if no axis is `true`.
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "({}: {}", stringify!($name), self.$first)?;
+                $(write!(f, ", {}", self.$field)?;)*
+                f.write_str(")")
+            }
+        }
+
+        impl Not for $name {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                Self { $first: !self.$first, $($field: !self.$field),* }
+            }
+        }
+
+        impl_bit_op!($name, [$first $(, $field)*], BitAnd::bitand, BitAndAssign::bitand_assign, &);
+        impl_bit_op!($name, [$first $(, $field)*], BitOr::bitor, BitOrAssign::bitor_assign, |);
+        impl_bit_op!($name, [$first $(, $field)*], BitXor::bitxor, BitXorAssign::bitxor_assign, ^);
+    };
+}
+
+/// Implements `$trait` and `$assign_trait` for the mask type `$name`.
+macro_rules! impl_bit_op {
+    ($name:ident, [$($field:ident),+], $trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident, $op:tt) => {
+        impl $trait for $name {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field $op rhs.$field),+ }
+            }
+        }
+
+        impl $assign_trait for $name {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+bool_coordinate!(
+    /// Result of a component-wise comparison, holding one `bool` per axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let a = Coordinate::new(1, 5, 3);
+    /// let b = Coordinate::new(2, 4, 3);
+    /// let mask = a.cmplt(b);
+    ///
+    /// assert_eq!(mask, BoolCoordinate::new(true, false, false));
+    /// assert!(mask.any() && !mask.all());
+    /// assert_eq!(Coordinate::select(mask, a, b), Coordinate::new(1, 4, 3));
+    /// ```
+    BoolCoordinate,
+    [x, y, z]
+);
+
+bool_coordinate!(
+    /// 2D counterpart of `BoolCoordinate`, produced by comparing 2D coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let a = Coordinate2::new(1, 5);
+    /// let mask = a.cmpge(Coordinate2::splat(2));
+    ///
+    /// assert_eq!(mask, BoolCoordinate2::new(false, true));
+    /// assert_eq!(Coordinate2::select(mask, a, Coordinate2::splat(2)), Coordinate2::new(2, 5));
+    /// ```
+    BoolCoordinate2,
+    [x, y]
+);
+
+/// Implements [`CoordinateCompare`] for `$name` with the mask type `$mask`.
+macro_rules! impl_compare {
+    ($name:ident, $sign:ident, $mask:ident, [$($field:ident),+]) => {
+        impl<T> CoordinateCompare for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            type Mask = $mask;
+
+            fn cmpeq(self, other: Self) -> $mask {
+                $mask { $($field: self.$field == other.$field),+ }
+            }
+
+            fn cmpne(self, other: Self) -> $mask {
+                $mask { $($field: self.$field != other.$field),+ }
+            }
+
+            fn cmplt(self, other: Self) -> $mask {
+                $mask { $($field: self.$field < other.$field),+ }
+            }
+
+            fn cmple(self, other: Self) -> $mask {
+                $mask { $($field: self.$field <= other.$field),+ }
+            }
+
+            fn cmpgt(self, other: Self) -> $mask {
+                $mask { $($field: self.$field > other.$field),+ }
+            }
+
+            fn cmpge(self, other: Self) -> $mask {
+                $mask { $($field: self.$field >= other.$field),+ }
+            }
+
+            fn select(mask: $mask, if_true: Self, if_false: Self) -> Self {
+                let pick = |mask: bool, a: T, b: T| if mask { a } else { b };
+                Self {
+                    $($field: pick(mask.$field, if_true.$field, if_false.$field)),+
+                }
+            }
+        }
+    };
+}
+
+impl_compare!(SignedCoordinate, Signed, BoolCoordinate, [x, y, z]);
+impl_compare!(UnsignedCoordinate, Unsigned, BoolCoordinate, [x, y, z]);
+impl_compare!(SignedCoordinate2, Signed, BoolCoordinate2, [x, y]);
+impl_compare!(UnsignedCoordinate2, Unsigned, BoolCoordinate2, [x, y]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinate::{Coordinate2Trait, CoordinateTrait};
+
+    #[test]
+    fn reductions() {
+        assert!(BoolCoordinate::splat(true).all());
+        assert!(BoolCoordinate::new(false, true, false).any());
+        assert!(BoolCoordinate::splat(false).none());
+        assert!(!BoolCoordinate::new(true, true, false).all());
+        assert!(BoolCoordinate2::new(false, true).any());
+        assert!(!BoolCoordinate2::new(false, true).all());
+    }
+
+    #[test]
+    fn operators() {
+        let a = BoolCoordinate::new(true, true, false);
+        let b = BoolCoordinate::new(true, false, false);
+        assert_eq!(a & b, BoolCoordinate::new(true, false, false));
+        assert_eq!(a | b, BoolCoordinate::new(true, true, false));
+        assert_eq!(a ^ b, BoolCoordinate::new(false, true, false));
+        assert_eq!(!a, BoolCoordinate::new(false, false, true));
+
+        let mut c = BoolCoordinate2::new(true, false);
+        c ^= BoolCoordinate2::splat(true);
+        assert_eq!(c, BoolCoordinate2::new(false, true));
+    }
+
+    #[test]
+    fn comparisons() {
+        let a = UnsignedCoordinate::new(1u8, 2, 3);
+        let b = UnsignedCoordinate::new(3u8, 2, 1);
+        assert_eq!(a.cmpeq(b), BoolCoordinate::new(false, true, false));
+        assert_eq!(a.cmpne(b), BoolCoordinate::new(true, false, true));
+        assert_eq!(a.cmple(b), BoolCoordinate::new(true, true, false));
+        assert_eq!(a.cmpgt(b), BoolCoordinate::new(false, false, true));
+        assert_eq!(a.cmpge(b), BoolCoordinate::new(false, true, true));
+
+        let mask = a.cmpgt(b);
+        assert_eq!(
+            UnsignedCoordinate::select(mask, a, b),
+            UnsignedCoordinate::new(3, 2, 3)
+        );
+    }
+
+    #[test]
+    fn comparisons_2d() {
+        let a = SignedCoordinate2::new(-1, 4);
+        let b = SignedCoordinate2::new(2, 4);
+        assert_eq!(a.cmplt(b), BoolCoordinate2::new(true, false));
+        assert_eq!(a.cmpeq(b), BoolCoordinate2::new(false, true));
+        assert_eq!(
+            SignedCoordinate2::select(a.cmpgt(b), a, b),
+            SignedCoordinate2::new(2, 4)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            BoolCoordinate::new(true, false, true).to_string(),
+            "(BoolCoordinate: true, false, true)"
+        );
+        assert_eq!(
+            BoolCoordinate2::new(false, true).to_string(),
+            "(BoolCoordinate2: false, true)"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use core::{
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use num::{Signed, Unsigned};
+
+use super::{
+    axis::Axis,
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::CoordinateField,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Implements the per-component helpers for `$name`.
+macro_rules! impl_components {
+    ($name:ident, $sign:ident, [x, y, z]) => {
+        impl_components!(@common $name, $sign, [x, y, z]);
+
+        impl<T> $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            /// Returns a copy with `z` replaced.
+            pub fn with_z(self, z: T) -> Self {
+                Self { z, ..self }
+            }
+
+            /// Returns `(x, z, y)`.
+            pub fn xzy(self) -> Self {
+                Self {
+                    x: self.x,
+                    y: self.z,
+                    z: self.y,
+                }
+            }
+
+            /// Returns `(y, x, z)`.
+            pub fn yxz(self) -> Self {
+                Self {
+                    x: self.y,
+                    y: self.x,
+                    z: self.z,
+                }
+            }
+
+            /// Returns `(y, z, x)`.
+            pub fn yzx(self) -> Self {
+                Self {
+                    x: self.y,
+                    y: self.z,
+                    z: self.x,
+                }
+            }
+
+            /// Returns `(z, x, y)`.
+            pub fn zxy(self) -> Self {
+                Self {
+                    x: self.z,
+                    y: self.x,
+                    z: self.y,
+                }
+            }
+
+            /// Returns `(z, y, x)`.
+            pub fn zyx(self) -> Self {
+                Self {
+                    x: self.z,
+                    y: self.y,
+                    z: self.x,
+                }
+            }
+        }
+    };
+    ($name:ident, $sign:ident, [x, y]) => {
+        impl_components!(@common $name, $sign, [x, y]);
+
+        impl<T> $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            /// Returns `(y, x)`.
+            pub fn yx(self) -> Self {
+                Self {
+                    x: self.y,
+                    y: self.x,
+                }
+            }
+        }
+    };
+    (@common $name:ident, $sign:ident, [$($field:ident),+]) => {
+        impl<T> $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            /// Applies `f` to every axis, possibly changing the field type.
+            pub fn map<U, F>(self, mut f: F) -> $name<U>
+            where
+                F: FnMut(T) -> U,
+                U: CoordinateField + $sign,
+            {
+                $name {
+                    $($field: f(self.$field),)+
+                }
+            }
+
+            /// Applies the fallible `f` to every axis, returning the first error.
+            pub fn try_map<U, E, F>(self, mut f: F) -> Result<$name<U>, E>
+            where
+                F: FnMut(T) -> Result<U, E>,
+                U: CoordinateField + $sign,
+            {
+                Ok($name {
+                    $($field: f(self.$field)?,)+
+                })
+            }
+
+            /// Combines the same axis of both coordinates using `f`.
+            pub fn zip_with<U, F>(self, other: Self, mut f: F) -> $name<U>
+            where
+                F: FnMut(T, T) -> U,
+                U: CoordinateField + $sign,
+            {
+                $name {
+                    $($field: f(self.$field, other.$field),)+
+                }
+            }
+
+            /// Folds the axes in the order `x`, `y`, `z`.
+            pub fn fold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, T) -> B,
+            {
+                [$(self.$field),+].into_iter().fold(init, f)
+            }
+
+            /// Iterates over the axes in the order `x`, `y`, `z`.
+            pub fn iter(&self) -> slice::Iter<'_, T> {
+                self.as_slice().iter()
+            }
+
+            /// Mutably iterates over the axes in the order `x`, `y`, `z`.
+            pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+                self.as_mut_slice().iter_mut()
+            }
+
+            /// Returns a copy with `x` replaced.
+            pub fn with_x(self, x: T) -> Self {
+                Self { x, ..self }
+            }
+
+            /// Returns a copy with `y` replaced.
+            pub fn with_y(self, y: T) -> Self {
+                Self { y, ..self }
+            }
+        }
+
+        /// # Panics
+        ///
+        /// Indexing panics if this type has no such axis, e.g. [`Axis::Z`] for 2D coordinates.
+        impl<T> Index<Axis> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            type Output = T;
+
+            fn index(&self, axis: Axis) -> &Self::Output {
+                self.as_slice()
+                    .get(axis.index())
+                    .unwrap_or_else(|| panic!("{} has no {axis} axis", stringify!($name)))
+            }
+        }
+
+        impl<T> IndexMut<Axis> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn index_mut(&mut self, axis: Axis) -> &mut Self::Output {
+                self.as_mut_slice()
+                    .get_mut(axis.index())
+                    .unwrap_or_else(|| panic!("{} has no {axis} axis", stringify!($name)))
+            }
+        }
+    };
+}
+
+impl_components!(SignedCoordinate, Signed, [x, y, z]);
+impl_components!(UnsignedCoordinate, Unsigned, [x, y, z]);
+impl_components!(SignedCoordinate2, Signed, [x, y]);
+impl_components!(UnsignedCoordinate2, Unsigned, [x, y]);
@@ -0,0 +1,185 @@
+use min_max_traits::{Max, Min};
+use num::{traits::AsPrimitive, Signed, Unsigned};
+use thiserror::Error;
+
+use super::{
+    axis::Axis,
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::{Coordinate2Trait, CoordinateField, CoordinateTrait},
+    generate::field_axis,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("The {axis} axis doesn't fit into the target coordinate's field type.")]
+pub struct TryFromCoordinateError {
+    pub axis: Axis,
+}
+
+/// Implements the lossless `From` conversions from `$from<$source>` to `$to<$target>`.
+macro_rules! impl_from {
+    ($from:ident => $to:ident, $fields:tt: $($source:ty => [$($target:ty),*]),* $(,)?) => {
+        $($(
+            impl_from!(@impl $from<$source> => $to<$target>, $fields);
+        )*)*
+    };
+    (@impl $from:ty => $to:ty, [$($field:ident),+]) => {
+        impl From<$from> for $to {
+            fn from(coordinate: $from) -> Self {
+                Self {
+                    $($field: coordinate.$field.into(),)+
+                }
+            }
+        }
+    };
+}
+
+/// Implements the fallible `TryFrom` conversions from `$from<$source>` to `$to<$target>`.
+macro_rules! impl_try_from {
+    ($from:ident => $to:ident, $fields:tt: $($source:ty => [$($target:ty),*]),* $(,)?) => {
+        $($(
+            impl_try_from!(@impl $from<$source> => $to<$target>, $target, $fields);
+        )*)*
+    };
+    (@impl $from:ty => $to:ty, $target:ty, [$($field:ident),+]) => {
+        impl TryFrom<$from> for $to {
+            type Error = TryFromCoordinateError;
+
+            fn try_from(coordinate: $from) -> Result<Self, Self::Error> {
+                Ok(Self {
+                    $($field: <$target>::try_from(coordinate.$field).map_err(|_| {
+                        TryFromCoordinateError {
+                            axis: field_axis!($field),
+                        }
+                    })?,)+
+                })
+            }
+        }
+    };
+}
+
+/// Implements all lossless and fallible conversions between the field types of `$signed` and
+/// `$unsigned`, which have the fields `$fields`.
+macro_rules! impl_conversions {
+    ($signed:ident, $unsigned:ident, $fields:tt) => {
+        impl_from!($signed => $signed, $fields:
+            i8 => [i16, i32, i64, i128, isize],
+            i16 => [i32, i64, i128, isize],
+            i32 => [i64, i128],
+            i64 => [i128],
+        );
+
+        impl_from!($unsigned => $unsigned, $fields:
+            u8 => [u16, u32, u64, u128, usize],
+            u16 => [u32, u64, u128, usize],
+            u32 => [u64, u128],
+            u64 => [u128],
+        );
+
+        impl_from!($unsigned => $signed, $fields:
+            u8 => [i16, i32, i64, i128, isize],
+            u16 => [i32, i64, i128],
+            u32 => [i64, i128],
+            u64 => [i128],
+        );
+
+        impl_try_from!($signed => $signed, $fields:
+            i16 => [i8],
+            i32 => [i8, i16, isize],
+            i64 => [i8, i16, i32, isize],
+            i128 => [i8, i16, i32, i64, isize],
+            isize => [i8, i16, i32, i64, i128],
+        );
+
+        impl_try_from!($unsigned => $unsigned, $fields:
+            u16 => [u8],
+            u32 => [u8, u16, usize],
+            u64 => [u8, u16, u32, usize],
+            u128 => [u8, u16, u32, u64, usize],
+            usize => [u8, u16, u32, u64, u128],
+        );
+
+        impl_try_from!($unsigned => $signed, $fields:
+            u8 => [i8],
+            u16 => [i8, i16, isize],
+            u32 => [i8, i16, i32, isize],
+            u64 => [i8, i16, i32, i64, isize],
+            u128 => [i8, i16, i32, i64, i128, isize],
+            usize => [i8, i16, i32, i64, i128, isize],
+        );
+
+        impl_try_from!($signed => $unsigned, $fields:
+            i8 => [u8, u16, u32, u64, u128, usize],
+            i16 => [u8, u16, u32, u64, u128, usize],
+            i32 => [u8, u16, u32, u64, u128, usize],
+            i64 => [u8, u16, u32, u64, u128, usize],
+            i128 => [u8, u16, u32, u64, u128, usize],
+            isize => [u8, u16, u32, u64, u128, usize],
+        );
+    };
+}
+
+impl_conversions!(SignedCoordinate, UnsignedCoordinate, [x, y, z]);
+impl_conversions!(SignedCoordinate2, UnsignedCoordinate2, [x, y]);
+
+/// Implements the casts into any coordinate type implementing `$trait` for `$name`.
+macro_rules! impl_casts {
+    ($name:ident, $sign:ident, $trait:ident, [$($field:ident),+]) => {
+        impl<T> $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            /// Converts into the coordinate type `C`, clamping every axis to the range of
+            /// `C::FieldType`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use argentum_game_coordinate_system::prelude::*;
+            ///
+            /// let coordinate = SignedCoordinate::new(-1, 200, 300);
+            /// let cast = coordinate.cast_saturating::<UnsignedCoordinate<u8>>();
+            /// assert_eq!(cast, UnsignedCoordinate::new(0, 200, 255));
+            /// ```
+            pub fn cast_saturating<C>(self) -> C
+            where
+                C: $trait<Type = C>,
+                C::FieldType: TryFrom<T> + Max + Min,
+            {
+                let cast = |n: T| {
+                    C::FieldType::try_from(n).unwrap_or_else(|_| match n < T::zero() {
+                        true => C::FieldType::MIN,
+                        false => C::FieldType::MAX,
+                    })
+                };
+                C::new($(cast(self.$field)),+)
+            }
+
+            /// Converts into the coordinate type `C`, wrapping around every axis like `as` does.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use argentum_game_coordinate_system::prelude::*;
+            ///
+            /// let coordinate = SignedCoordinate::new(-1, 200, 300);
+            /// let cast = coordinate.cast_wrapping::<UnsignedCoordinate<u8>>();
+            /// assert_eq!(cast, UnsignedCoordinate::new(255, 200, 44));
+            /// ```
+            pub fn cast_wrapping<C>(self) -> C
+            where
+                C: $trait<Type = C>,
+                C::FieldType: Copy + 'static,
+                T: AsPrimitive<C::FieldType>,
+            {
+                C::new($(self.$field.as_()),+)
+            }
+        }
+    };
+}
+
+impl_casts!(SignedCoordinate, Signed, CoordinateTrait, [x, y, z]);
+impl_casts!(UnsignedCoordinate, Unsigned, CoordinateTrait, [x, y, z]);
+impl_casts!(SignedCoordinate2, Signed, Coordinate2Trait, [x, y]);
+impl_casts!(UnsignedCoordinate2, Unsigned, Coordinate2Trait, [x, y]);
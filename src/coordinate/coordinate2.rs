@@ -0,0 +1,158 @@
+use num::{Signed, Unsigned};
+
+use super::{
+    axis::Axis,
+    coordinate_trait::CoordinateField,
+    generate::{coordinate_type, impl_neg},
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Implements the projections between the 2D type `$name` and the 3D type `$name3`.
+macro_rules! impl_projection {
+    ($name:ident, $name3:ident, $sign:ident) => {
+        impl<T> $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            /// Adds `height` on `axis`, turning this into a 3D coordinate.
+            ///
+            /// `x` and `y` fill the remaining two axes in the order `x, y, z`, undoing
+            #[doc = concat!("[`", stringify!($name3), "::project`].")]
+            pub fn lift(self, axis: Axis, height: T) -> $name3<T> {
+                let Self { x, y } = self;
+                match axis {
+                    Axis::X => $name3 {
+                        x: height,
+                        y: x,
+                        z: y,
+                    },
+                    Axis::Y => $name3 { x, y: height, z: y },
+                    Axis::Z => $name3 { x, y, z: height },
+                }
+            }
+        }
+
+        impl<T> $name3<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            /// Drops `axis`, projecting this onto the plane perpendicular to it.
+            ///
+            /// The remaining two axes keep their order, so projecting along `Axis::Y` maps `x`
+            /// to `x` and `z` to `y`.
+            pub fn project(self, axis: Axis) -> $name<T> {
+                let Self { x, y, z } = self;
+                match axis {
+                    Axis::X => $name { x: y, y: z },
+                    Axis::Y => $name { x, y: z },
+                    Axis::Z => $name { x, y },
+                }
+            }
+        }
+    };
+}
+
+coordinate_type!(
+    /// 2D counterpart of `SignedCoordinate`, e.g. for columns, heightmaps and map tiles.
+    ///
+    /// Offers the same operators, math, conversions, parsing and formatting as the 3D types.
+    /// Comparisons produce a `BoolCoordinate2`. The `glam`/`mint`/`nalgebra` interop remains 3D
+    /// only. `Rectangle` is the 2D counterpart of `Region`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let coordinate = Coordinate::new(1, 64, 3);
+    /// let column = coordinate.project(Axis::Y);
+    /// assert_eq!(column, Coordinate2::new(1, 3));
+    /// assert_eq!(column + Coordinate2::splat(1), Coordinate2::new(2, 4));
+    /// assert_eq!(column.lift(Axis::Y, 64), coordinate);
+    /// assert_eq!("1, 3".parse(), Ok(column));
+    /// ```
+    SignedCoordinate2,
+    Signed,
+    [x, y],
+    SignedCoordinate
+);
+impl_neg!(SignedCoordinate2, [x, y]);
+impl_projection!(SignedCoordinate2, SignedCoordinate, Signed);
+
+coordinate_type!(
+    /// 2D counterpart of `UnsignedCoordinate`.
+    UnsignedCoordinate2,
+    Unsigned,
+    [x, y],
+    UnsignedCoordinate
+);
+impl_projection!(UnsignedCoordinate2, UnsignedCoordinate, Unsigned);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::coordinate::{Coordinate2Trait, CoordinateMath, CoordinateTrait};
+
+    #[test]
+    fn arithmetic() {
+        let a = SignedCoordinate2::new(6, -4);
+        let b = SignedCoordinate2::new(2, 3);
+        assert_eq!(a + b, SignedCoordinate2::new(8, -1));
+        assert_eq!(a - b, SignedCoordinate2::new(4, -7));
+        assert_eq!(a * 2, SignedCoordinate2::new(12, -8));
+        assert_eq!(a / b, SignedCoordinate2::new(3, -1));
+        assert_eq!(a % b, SignedCoordinate2::new(0, -1));
+        assert_eq!(-a, SignedCoordinate2::new(-6, 4));
+
+        let mut c = UnsignedCoordinate2::new(0b1100u8, 0b1010);
+        c &= UnsignedCoordinate2::splat(0b0110);
+        assert_eq!(c, UnsignedCoordinate2::new(0b0100, 0b0010));
+        assert_eq!(!c, UnsignedCoordinate2::new(0b1111_1011, 0b1111_1101));
+    }
+
+    #[test]
+    #[should_panic(expected = "(UnsignedCoordinate2: 255, 255) is experiencing integer overflow.")]
+    fn overflow() {
+        let _ = UnsignedCoordinate2::<u8>::MAX + UnsignedCoordinate2::splat(1);
+    }
+
+    #[test]
+    fn math() {
+        let a = SignedCoordinate2::new(3, -2);
+        let b = SignedCoordinate2::new(-1, 4);
+        assert_eq!(a.min(b), SignedCoordinate2::new(-1, -2));
+        assert_eq!(a.abs(), SignedCoordinate2::new(3, 2));
+        assert_eq!(a.dot(b), -11);
+        assert_eq!(a.max_element(), 3);
+        assert_eq!(a << 1, SignedCoordinate2::new(6, -4));
+        assert_eq!(a.map(|n| n * 2).yx(), SignedCoordinate2::new(-4, 6));
+        assert_eq!(
+            UnsignedCoordinate2::new(300u16, 7).cast_saturating::<UnsignedCoordinate2<u8>>(),
+            UnsignedCoordinate2::new(255, 7)
+        );
+    }
+
+    #[test]
+    fn projections() {
+        let coordinate = UnsignedCoordinate::new(1u8, 2, 3);
+        for axis in Axis::ALL {
+            assert_eq!(
+                coordinate.project(axis).lift(axis, coordinate[axis]),
+                coordinate
+            );
+        }
+        assert_eq!(coordinate.project(Axis::X), UnsignedCoordinate2::new(2, 3));
+        assert_eq!(coordinate.project(Axis::Z), UnsignedCoordinate2::new(1, 2));
+    }
+
+    #[test]
+    fn display_and_order() {
+        let a = SignedCoordinate2::new(5, 1);
+        let b = SignedCoordinate2::new(1, 2);
+        assert_eq!(a.to_string(), "(SignedCoordinate2: 5, 1)");
+        assert!(a < b);
+        assert_eq!(<[i32; 2]>::from(a), [5, 1]);
+        assert_eq!(SignedCoordinate2::from((5, 1)), a);
+    }
+}
@@ -0,0 +1,288 @@
+use core::{
+    array,
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+        DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Sub, SubAssign,
+    },
+    slice,
+};
+
+use min_max_traits::{Max, Min};
+use num::{traits::CheckedNeg, Signed, Unsigned};
+
+use super::{
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::CoordinateField,
+    generate::overflow,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Coordinate with `N` axes, e.g. for 4D simulations with a time axis.
+///
+/// Arithmetic is component-wise and panics on overflow, like the 3D types. The operators require
+/// the same [`CoordinateField`] bounds as theirs.
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let a = CoordinateN::new([1, 2, 3, 4]);
+/// let b = CoordinateN::splat(1);
+/// assert_eq!(a + b, CoordinateN::new([2, 3, 4, 5]));
+/// assert_eq!(a[3], 4);
+///
+/// let spatial: CoordinateN<i32, 3> = Coordinate::new(1, 2, 3).into();
+/// assert_eq!(Coordinate::from(spatial), Coordinate::new(1, 2, 3));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct CoordinateN<T, const N: usize>(pub [T; N]);
+
+impl<T: Copy, const N: usize> CoordinateN<T, N> {
+    /// Creates a new `CoordinateN`.
+    pub fn new(fields: [T; N]) -> Self {
+        Self(fields)
+    }
+
+    /// Creates a new `CoordinateN` with every axis set to `n`.
+    pub fn splat(n: T) -> Self {
+        Self([n; N])
+    }
+
+    /// Returns an iterator over the axes.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator that allows modifying each axis.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    /// Applies `f` to every axis.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> CoordinateN<U, N> {
+        CoordinateN(self.0.map(f))
+    }
+
+    /// Combines the axes of `self` and `other` pairwise using `f`.
+    pub fn zip_with<U, F>(self, other: Self, mut f: F) -> CoordinateN<U, N>
+    where
+        F: FnMut(T, T) -> U,
+    {
+        CoordinateN(array::from_fn(|axis| f(self.0[axis], other.0[axis])))
+    }
+}
+
+impl<T: Max + Min, const N: usize> CoordinateN<T, N> {
+    /// Smallest possible value on all axes.
+    pub const MIN: Self = Self([T::MIN; N]);
+
+    /// Largest possible value on all axes.
+    pub const MAX: Self = Self([T::MAX; N]);
+}
+
+impl<T: Display, const N: usize> Display for CoordinateN<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("(CoordinateN:")?;
+        for (axis, n) in self.0.iter().enumerate() {
+            let separator = if axis == 0 { " " } else { ", " };
+            write!(f, "{separator}{n}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl<T: Ord, const N: usize> PartialOrd for CoordinateN<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the last axis first, matching the iteration order of `HyperRectangle`.
+impl<T: Ord, const N: usize> Ord for CoordinateN<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl<T, const N: usize> Index<usize> for CoordinateN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for CoordinateN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for CoordinateN<T, N> {
+    fn from(fields: [T; N]) -> Self {
+        Self(fields)
+    }
+}
+
+impl<T, const N: usize> From<CoordinateN<T, N>> for [T; N] {
+    fn from(coordinate: CoordinateN<T, N>) -> Self {
+        coordinate.0
+    }
+}
+
+impl<T: CoordinateField, const N: usize> Not for CoordinateN<T, N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.map(|n| !n)
+    }
+}
+
+impl<T: CoordinateField + CheckedNeg, const N: usize> Neg for CoordinateN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|n| n.checked_neg().unwrap_or_else(|| overflow(&self)))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: quickcheck::Arbitrary, const N: usize> quickcheck::Arbitrary for CoordinateN<T, N> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self(array::from_fn(|_| T::arbitrary(g)))
+    }
+}
+
+/// Implements `$trait` and `$assign_trait` for `CoordinateN`, with another `CoordinateN` or a
+/// single `T` as the right-hand side.
+///
+/// `$op` returns `None` if the operation overflows, which panics.
+macro_rules! impl_op {
+    ($trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident, |$a:ident, $b:ident| $op:expr) => {
+        impl<T: CoordinateField, const N: usize> $trait for CoordinateN<T, N> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.zip_with(rhs, |$a, $b| $op.unwrap_or_else(|| overflow(&self)))
+            }
+        }
+
+        impl<T: CoordinateField, const N: usize> $trait<T> for CoordinateN<T, N> {
+            type Output = Self;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                $trait::$method(self, Self::splat(rhs))
+            }
+        }
+
+        impl<T: CoordinateField, const N: usize> $assign_trait for CoordinateN<T, N> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+
+        impl<T: CoordinateField, const N: usize> $assign_trait<T> for CoordinateN<T, N> {
+            fn $assign_method(&mut self, rhs: T) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_op!(Add::add, AddAssign::add_assign, |a, b| a.checked_add(&b));
+impl_op!(Sub::sub, SubAssign::sub_assign, |a, b| a.checked_sub(&b));
+impl_op!(Mul::mul, MulAssign::mul_assign, |a, b| a.checked_mul(&b));
+impl_op!(Div::div, DivAssign::div_assign, |a, b| a.checked_div(&b));
+impl_op!(Rem::rem, RemAssign::rem_assign, |a, b| Some(a % b));
+impl_op!(BitAnd::bitand, BitAndAssign::bitand_assign, |a, b| Some(
+    a & b
+));
+impl_op!(BitOr::bitor, BitOrAssign::bitor_assign, |a, b| Some(a | b));
+impl_op!(BitXor::bitxor, BitXorAssign::bitxor_assign, |a, b| Some(
+    a ^ b
+));
+
+/// Implements the conversions between `$name` and `CoordinateN<T, $n>`.
+macro_rules! impl_conversions {
+    ($name:ident, $sign:ident, $n:literal, [$($field:ident),*]) => {
+        impl<T> From<$name<T>> for CoordinateN<T, $n>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn from(coordinate: $name<T>) -> Self {
+                Self([$(coordinate.$field),*])
+            }
+        }
+
+        impl<T> From<CoordinateN<T, $n>> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn from(CoordinateN([$($field),*]): CoordinateN<T, $n>) -> Self {
+                Self { $($field),* }
+            }
+        }
+    };
+}
+
+impl_conversions!(SignedCoordinate, Signed, 3, [x, y, z]);
+impl_conversions!(UnsignedCoordinate, Unsigned, 3, [x, y, z]);
+impl_conversions!(SignedCoordinate2, Signed, 2, [x, y]);
+impl_conversions!(UnsignedCoordinate2, Unsigned, 2, [x, y]);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::coordinate::{Coordinate2Trait, CoordinateTrait};
+
+    #[test]
+    fn arithmetic() {
+        let a = CoordinateN::new([6, -4, 1, 0]);
+        let b = CoordinateN::new([2, 3, 1, 5]);
+        assert_eq!(a + b, CoordinateN::new([8, -1, 2, 5]));
+        assert_eq!(a - b, CoordinateN::new([4, -7, 0, -5]));
+        assert_eq!(a * 2, CoordinateN::new([12, -8, 2, 0]));
+        assert_eq!(a / b, CoordinateN::new([3, -1, 1, 0]));
+        assert_eq!(a % b, CoordinateN::new([0, -1, 0, 0]));
+        assert_eq!(-a, CoordinateN::new([-6, 4, -1, 0]));
+        assert_eq!(!CoordinateN::new([0u8]), CoordinateN::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "(CoordinateN: 255, 255) is experiencing integer overflow.")]
+    fn overflow() {
+        let _ = CoordinateN::<u8, 2>::MAX + 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn neg_overflow() {
+        let _ = -CoordinateN::new([0, i32::MIN]);
+    }
+
+    #[test]
+    fn conversions() {
+        let coordinate = UnsignedCoordinate::new(1u8, 2, 3);
+        let n = CoordinateN::from(coordinate);
+        assert_eq!(n, CoordinateN::new([1, 2, 3]));
+        assert_eq!(UnsignedCoordinate::from(n), coordinate);
+        assert_eq!(
+            SignedCoordinate2::from(CoordinateN::new([4, 5])),
+            SignedCoordinate2::new(4, 5)
+        );
+    }
+
+    #[test]
+    fn display_and_order() {
+        let a = CoordinateN::new([5, 1, 0, 0]);
+        let b = CoordinateN::new([1, 2, 0, 0]);
+        assert_eq!(a.to_string(), "(CoordinateN: 5, 1, 0, 0)");
+        assert_eq!(CoordinateN::<u8, 0>::new([]).to_string(), "(CoordinateN:)");
+        assert!(a < b);
+    }
+}
@@ -1,31 +1,92 @@
-use std::{fmt::Display, ops::*};
+use core::{fmt::Display, ops::*};
 
-use quickcheck::Arbitrary;
+use min_max_traits::{Max, Min};
+use num::{
+    traits::{CheckedShl, CheckedShr},
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Integer, ToPrimitive,
+};
 
+use super::{
+    bool_coordinate::{BoolCoordinate, BoolCoordinate2, CoordinateCompare},
+    math::{Coordinate3Math, CoordinateMath},
+};
+
+/// Field type of the coordinate types of this crate.
+///
+/// Bundles the bounds every field needs, so they read `T: CoordinateField + Signed` instead of
+/// listing all of them. Implemented for every type meeting them, e.g. all primitive integers.
+pub trait CoordinateField:
+    Integer
+    + Copy
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + CheckedDiv
+    + CheckedShl
+    + CheckedShr
+    + ToPrimitive
+    + Display
+    + Max
+    + Min
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+{
+}
+
+impl<T> CoordinateField for T where
+    T: Integer
+        + Copy
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + CheckedDiv
+        + CheckedShl
+        + CheckedShr
+        + ToPrimitive
+        + Display
+        + Max
+        + Min
+        + BitAnd<Output = T>
+        + BitOr<Output = T>
+        + BitXor<Output = T>
+        + Not<Output = T>
+{
+}
+
+/// Common interface of the 3D coordinate types.
 pub trait CoordinateTrait:
-    Sized
-    + Add
+    Coordinate3Math
+    + CoordinateCompare<Mask = BoolCoordinate>
+    + Add<Output = Self>
     + AddAssign
-    + Sub
+    + Sub<Output = Self>
     + SubAssign
-    + Mul
+    + Mul<Output = Self>
     + MulAssign
-    + Div
+    + Div<Output = Self>
     + DivAssign
-    + Rem
+    + Rem<Output = Self>
     + RemAssign
-    + BitAnd
+    + BitAnd<Output = Self>
     + BitAndAssign
-    + BitOr
+    + BitOr<Output = Self>
     + BitOrAssign
-    + BitXor
+    + BitXor<Output = Self>
     + BitXorAssign
-    + Not
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + ShlAssign<u32>
+    + Shl<Output = Self>
+    + ShlAssign
+    + Shr<u32, Output = Self>
+    + ShrAssign<u32>
+    + Shr<Output = Self>
+    + ShrAssign
     + Display
-    + Arbitrary
 {
     type Type;
-    type FieldType;
 
     const MAX: Self::Type;
     const MIN: Self::Type;
@@ -33,3 +94,50 @@ pub trait CoordinateTrait:
     fn new(x: Self::FieldType, y: Self::FieldType, z: Self::FieldType) -> Self::Type;
     fn splat(n: Self::FieldType) -> Self::Type;
 }
+
+/// 2D counterpart of [`CoordinateTrait`], implemented by `SignedCoordinate2` and
+/// `UnsignedCoordinate2`.
+pub trait Coordinate2Trait:
+    CoordinateMath
+    + CoordinateCompare<Mask = BoolCoordinate2>
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Div<Output = Self>
+    + DivAssign
+    + Rem<Output = Self>
+    + RemAssign
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + BitXor<Output = Self>
+    + BitXorAssign
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + ShlAssign<u32>
+    + Shl<Output = Self>
+    + ShlAssign
+    + Shr<u32, Output = Self>
+    + ShrAssign<u32>
+    + Shr<Output = Self>
+    + ShrAssign
+    + Display
+{
+    type Type;
+    /// The 3D coordinate type with the same field type, e.g. `SignedCoordinate<T>` for
+    /// `SignedCoordinate2<T>`.
+    type Coordinate3: CoordinateTrait<Type = Self::Coordinate3, FieldType = Self::FieldType>
+        + Copy
+        + PartialEq
+        + Into<[Self::FieldType; 3]>;
+
+    const MAX: Self::Type;
+    const MIN: Self::Type;
+
+    fn new(x: Self::FieldType, y: Self::FieldType) -> Self::Type;
+    fn splat(n: Self::FieldType) -> Self::Type;
+}
@@ -0,0 +1,222 @@
+use core::{
+    fmt::{self, Display, Formatter, LowerHex},
+    num::NonZero,
+};
+
+use num::{NumCast, Signed, Unsigned};
+
+use super::{
+    axis::Axis,
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::CoordinateField,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Layout of a formatted coordinate.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub enum FormatStyle {
+    /// `(SignedCoordinate: 1, 2, 3)`, the same as `Display`.
+    #[default]
+    Verbose,
+    /// `1 2 3`, e.g. for HUD overlays.
+    Compact,
+    /// `{"x":1,"y":2,"z":3}`.
+    Json,
+}
+
+/// Options for [`SignedCoordinate::format_with`], [`UnsignedCoordinate::format_with`], their 2D
+/// counterparts and [`Region::format_with`](crate::region::Region::format_with).
+///
+/// Width, fill, alignment and `#` flags of the format string are applied to every number, so
+/// `{:>4}` pads each axis and `{:#}` prefixes hex numbers with `0x`.
+///
+/// # Examples
+///
+/// ```
+/// use core::num::NonZero;
+///
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let coordinate = Coordinate::new(17, -1, 255);
+///
+/// let compact = coordinate.format_with(CoordinateFormat::compact());
+/// assert_eq!(format!("{compact:>3}"), " 17  -1 255");
+///
+/// let json = coordinate.format_with(CoordinateFormat::json());
+/// assert_eq!(json.to_string(), r#"{"x":17,"y":-1,"z":255}"#);
+///
+/// let hex = coordinate.format_with(CoordinateFormat::compact().hex(true));
+/// assert_eq!(format!("{hex:#}"), "0x11 0xffffffff 0xff");
+///
+/// let chunks = CoordinateFormat::new().chunk_size(NonZero::new(16).unwrap());
+/// let chunks = coordinate.format_with(chunks);
+/// assert_eq!(chunks.to_string(), "(SignedCoordinate: 1:1, -1:15, 15:15)");
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct CoordinateFormat {
+    pub(crate) style: FormatStyle,
+    hex: bool,
+    chunk_size: Option<NonZero<u32>>,
+}
+
+impl CoordinateFormat {
+    /// Creates a new `CoordinateFormat` matching `Display`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `CoordinateFormat` with the [`FormatStyle::Compact`] style.
+    pub fn compact() -> Self {
+        Self::new().style(FormatStyle::Compact)
+    }
+
+    /// Creates a new `CoordinateFormat` with the [`FormatStyle::Json`] style.
+    pub fn json() -> Self {
+        Self::new().style(FormatStyle::Json)
+    }
+
+    /// Sets the layout. Defaults to [`FormatStyle::Verbose`].
+    pub fn style(mut self, style: FormatStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets whether numbers are printed in lowercase hex, like `{:x}` does.
+    ///
+    /// Negative numbers are printed in two's complement. Hex numbers aren't quoted, so
+    /// [`FormatStyle::Json`] only produces JSON-like output with this.
+    pub fn hex(mut self, hex: bool) -> Self {
+        self.hex = hex;
+        self
+    }
+
+    /// Prints every axis as `chunk:local`, the chunk index and the position within that chunk.
+    ///
+    /// Chunk sizes that don't fit into the field type are ignored, as every value of that type
+    /// would be in the same chunk.
+    pub fn chunk_size(mut self, chunk_size: NonZero<u32>) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Writes the fields of a coordinate, `name` being the type used by the verbose style.
+    pub(crate) fn write_coordinate<T, const N: usize>(
+        &self,
+        f: &mut Formatter<'_>,
+        name: &str,
+        fields: [T; N],
+    ) -> fmt::Result
+    where
+        T: CoordinateField + NumCast + LowerHex,
+    {
+        let separator = match self.style {
+            FormatStyle::Verbose => {
+                write!(f, "({name}: ")?;
+                ", "
+            }
+            FormatStyle::Compact => " ",
+            FormatStyle::Json => {
+                f.write_str("{")?;
+                ","
+            }
+        };
+        for (axis, n) in Axis::ALL.into_iter().zip(fields) {
+            if axis != Axis::X {
+                f.write_str(separator)?;
+            }
+            if self.style == FormatStyle::Json {
+                write!(f, "\"{axis}\":")?;
+            }
+            self.write_field(f, n)?;
+        }
+        match self.style {
+            FormatStyle::Verbose => f.write_str(")"),
+            FormatStyle::Compact => Ok(()),
+            FormatStyle::Json => f.write_str("}"),
+        }
+    }
+
+    fn write_field<T>(&self, f: &mut Formatter<'_>, n: T) -> fmt::Result
+    where
+        T: CoordinateField + NumCast + LowerHex,
+    {
+        let chunk_size = self
+            .chunk_size
+            .and_then(|chunk_size| <T as NumCast>::from(chunk_size.get()));
+        let Some(chunk_size) = chunk_size else {
+            return self.write_number(f, n);
+        };
+
+        let (chunk, local) = n.div_mod_floor(&chunk_size);
+        let (open, separator, close) = match self.style {
+            FormatStyle::Json => ("{\"chunk\":", ",\"local\":", "}"),
+            FormatStyle::Verbose | FormatStyle::Compact => ("", ":", ""),
+        };
+        f.write_str(open)?;
+        self.write_number(f, chunk)?;
+        f.write_str(separator)?;
+        self.write_number(f, local)?;
+        f.write_str(close)
+    }
+
+    /// Writes a single number, honouring the flags of `f`.
+    pub(crate) fn write_number<T: Display + LowerHex>(
+        &self,
+        f: &mut Formatter<'_>,
+        n: T,
+    ) -> fmt::Result {
+        match self.hex {
+            true => LowerHex::fmt(&n, f),
+            false => Display::fmt(&n, f),
+        }
+    }
+}
+
+/// A coordinate formatted according to a [`CoordinateFormat`], created by `format_with`.
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedCoordinate<'a, C> {
+    coordinate: &'a C,
+    format: CoordinateFormat,
+}
+
+impl<'a, C> FormattedCoordinate<'a, C> {
+    /// Formats `coordinate` according to `format`, for types wrapping coordinates.
+    pub(crate) fn new(coordinate: &'a C, format: CoordinateFormat) -> Self {
+        Self { coordinate, format }
+    }
+}
+
+/// Implements `format_with` for `$name`.
+macro_rules! impl_format {
+    ($name:ident, $sign:ident, [$($field:ident),+]) => {
+        impl<T> $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            /// Returns a value that formats this coordinate according to `format`.
+            pub fn format_with(&self, format: CoordinateFormat) -> FormattedCoordinate<'_, Self> {
+                FormattedCoordinate {
+                    coordinate: self,
+                    format,
+                }
+            }
+        }
+
+        impl<T> Display for FormattedCoordinate<'_, $name<T>>
+        where
+            T: CoordinateField + $sign + NumCast + LowerHex,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let $name { $($field),+ } = *self.coordinate;
+                self.format
+                    .write_coordinate(f, stringify!($name), [$($field),+])
+            }
+        }
+    };
+}
+
+impl_format!(SignedCoordinate, Signed, [x, y, z]);
+impl_format!(UnsignedCoordinate, Unsigned, [x, y, z]);
+impl_format!(SignedCoordinate2, Signed, [x, y]);
+impl_format!(UnsignedCoordinate2, Unsigned, [x, y]);
@@ -0,0 +1,225 @@
+/// Generates the coordinate type `$name` with the given fields and all of its operators.
+///
+/// Produces what the `Coordinate` derive produces, except that `quickcheck::Arbitrary` is only
+/// implemented if the field type implements it, instead of requiring it from every field type
+/// once the `quickcheck` feature is enabled. Types with the fields `x`, `y` and `z` implement
+/// [`CoordinateTrait`](super::CoordinateTrait), types with the fields `x` and `y` implement
+/// [`Coordinate2Trait`](super::Coordinate2Trait). Overflowing operators panic with the message
+/// "`<coordinate>` is experiencing integer overflow.", as they did with the derive.
+macro_rules! coordinate_type {
+    ($(#[$attr:meta])* $name:ident, $sign:ident, [x, y, z]) => {
+        $crate::coordinate::generate::coordinate_type!(@common $(#[$attr])* $name, $sign, [x, y, z]);
+
+        impl<T> $crate::coordinate::CoordinateTrait for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            type Type = Self;
+
+            const MAX: Self = Self {
+                x: T::MAX,
+                y: T::MAX,
+                z: T::MAX,
+            };
+            const MIN: Self = Self {
+                x: T::MIN,
+                y: T::MIN,
+                z: T::MIN,
+            };
+
+            fn new(x: T, y: T, z: T) -> Self {
+                Self { x, y, z }
+            }
+
+            fn splat(n: T) -> Self {
+                Self { x: n, y: n, z: n }
+            }
+        }
+    };
+    ($(#[$attr:meta])* $name:ident, $sign:ident, [x, y], $name3:ident) => {
+        $crate::coordinate::generate::coordinate_type!(@common $(#[$attr])* $name, $sign, [x, y]);
+
+        impl<T> $crate::coordinate::Coordinate2Trait for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            type Type = Self;
+            type Coordinate3 = $name3<T>;
+
+            const MAX: Self = Self { x: T::MAX, y: T::MAX };
+            const MIN: Self = Self { x: T::MIN, y: T::MIN };
+
+            fn new(x: T, y: T) -> Self {
+                Self { x, y }
+            }
+
+            fn splat(n: T) -> Self {
+                Self { x: n, y: n }
+            }
+        }
+    };
+    (@common $(#[$attr:meta])* $name:ident, $sign:ident, [$($field:ident),+]) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[repr(C)]
+        pub struct $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            $(pub $field: T,)+
+        }
+
+        impl<T> ::core::fmt::Display for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "({}: ", stringify!($name))?;
+                for (i, n) in [$(self.$field),+].into_iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{n}")?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        #[cfg(feature = "quickcheck")]
+        impl<T> quickcheck::Arbitrary for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign + quickcheck::Arbitrary,
+        {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                Self {
+                    $($field: T::arbitrary(g),)+
+                }
+            }
+        }
+
+        impl<T> ::core::ops::Not for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                Self {
+                    $($field: !self.$field,)+
+                }
+            }
+        }
+
+        $crate::coordinate::generate::impl_op!($name, $sign, [$($field),+], Add::add, AddAssign::add_assign, |a, b| a.checked_add(&b));
+        $crate::coordinate::generate::impl_op!($name, $sign, [$($field),+], Sub::sub, SubAssign::sub_assign, |a, b| a.checked_sub(&b));
+        $crate::coordinate::generate::impl_op!($name, $sign, [$($field),+], Mul::mul, MulAssign::mul_assign, |a, b| a.checked_mul(&b));
+        $crate::coordinate::generate::impl_op!($name, $sign, [$($field),+], Div::div, DivAssign::div_assign, |a, b| a.checked_div(&b));
+        $crate::coordinate::generate::impl_op!($name, $sign, [$($field),+], Rem::rem, RemAssign::rem_assign, |a, b| Some(a % b));
+        $crate::coordinate::generate::impl_op!($name, $sign, [$($field),+], BitAnd::bitand, BitAndAssign::bitand_assign, |a, b| Some(a & b));
+        $crate::coordinate::generate::impl_op!($name, $sign, [$($field),+], BitOr::bitor, BitOrAssign::bitor_assign, |a, b| Some(a | b));
+        $crate::coordinate::generate::impl_op!($name, $sign, [$($field),+], BitXor::bitxor, BitXorAssign::bitxor_assign, |a, b| Some(a ^ b));
+    };
+}
+
+/// Implements `$trait` and `$assign_trait` for `$name`, with another `$name` or a single `T` as
+/// the right-hand side.
+///
+/// `$op` returns `None` if the operation overflows, which panics.
+macro_rules! impl_op {
+    ($name:ident, $sign:ident, [$($field:ident),+], $trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident, |$a:ident, $b:ident| $op:expr) => {
+        impl<T> ::core::ops::$trait for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                let op = |$a: T, $b: T| $op;
+                Self {
+                    $($field: op(self.$field, rhs.$field).unwrap_or_else(|| $crate::coordinate::generate::overflow(&self)),)+
+                }
+            }
+        }
+
+        impl<T> ::core::ops::$trait<T> for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            type Output = Self;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                let op = |$a: T, $b: T| $op;
+                Self {
+                    $($field: op(self.$field, rhs).unwrap_or_else(|| $crate::coordinate::generate::overflow(&self)),)+
+                }
+            }
+        }
+
+        impl<T> ::core::ops::$assign_trait for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = ::core::ops::$trait::$method(*self, rhs);
+            }
+        }
+
+        impl<T> ::core::ops::$assign_trait<T> for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + $sign,
+        {
+            fn $assign_method(&mut self, rhs: T) {
+                *self = ::core::ops::$trait::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+/// Implements `Neg` for the signed coordinate type `$name`, panicking on overflow like the other
+/// operators.
+macro_rules! impl_neg {
+    ($name:ident, [$($field:ident),+]) => {
+        impl<T> ::core::ops::Neg for $name<T>
+        where
+            T: $crate::coordinate::CoordinateField + ::num::Signed + ::num::traits::CheckedNeg,
+        {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self {
+                    $($field: self.$field.checked_neg().unwrap_or_else(|| $crate::coordinate::generate::overflow(&self)),)+
+                }
+            }
+        }
+    };
+}
+
+/// Panics because an operator overflowed on `coordinate`, with the message the `Coordinate` derive
+/// used.
+#[cold]
+#[track_caller]
+pub(crate) fn overflow(coordinate: &impl core::fmt::Display) -> ! {
+    panic!("{coordinate} is experiencing integer overflow.")
+}
+
+/// Expands to the [`Axis`](super::Axis) of the field `$field`.
+macro_rules! field_axis {
+    (x) => {
+        $crate::coordinate::Axis::X
+    };
+    (y) => {
+        $crate::coordinate::Axis::Y
+    };
+    (z) => {
+        $crate::coordinate::Axis::Z
+    };
+}
+
+/// Expands to the field type `T` once per field, e.g. for tuple types.
+macro_rules! field_type {
+    ($field:ident) => {
+        T
+    };
+}
+
+pub(crate) use {coordinate_type, field_axis, field_type, impl_neg, impl_op};
@@ -0,0 +1,141 @@
+use core::slice;
+
+use num::{Signed, Unsigned};
+
+use super::{
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::CoordinateField,
+    generate::field_type,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Implements the array views and conversions relying on the `#[repr(C)]` layout of `$name`.
+macro_rules! impl_layout {
+    ($name:ident, $sign:ident, $n:literal, [$($field:ident),+]) => {
+        impl<T> $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            #[doc = concat!("Views the fields as `[", stringify!($($field),+), "]`.")]
+            pub fn as_array(&self) -> &[T; $n] {
+                // SAFETY: `#[repr(C)]` lays out fields of the same type `T` like an array.
+                unsafe { &*(self as *const Self).cast::<[T; $n]>() }
+            }
+
+            #[doc = concat!("Mutably views the fields as `[", stringify!($($field),+), "]`.")]
+            pub fn as_mut_array(&mut self) -> &mut [T; $n] {
+                // SAFETY: `#[repr(C)]` lays out fields of the same type `T` like an array.
+                unsafe { &mut *(self as *mut Self).cast::<[T; $n]>() }
+            }
+
+            #[doc = concat!("Views the fields as a slice of length ", $n, ".")]
+            pub fn as_slice(&self) -> &[T] {
+                self.as_array()
+            }
+
+            #[doc = concat!("Mutably views the fields as a slice of length ", $n, ".")]
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                self.as_mut_array()
+            }
+
+            /// Views `coordinates` as their fields, one coordinate after the other.
+            pub fn flatten_slice(coordinates: &[Self]) -> &[T] {
+                // SAFETY: `Self` is laid out like `[T; $n]`, so `n` coordinates are `$n * n` fields.
+                unsafe { slice::from_raw_parts(coordinates.as_ptr().cast(), coordinates.len() * $n) }
+            }
+
+            /// Mutably views `coordinates` as their fields, one coordinate after the other.
+            pub fn flatten_mut_slice(coordinates: &mut [Self]) -> &mut [T] {
+                // SAFETY: `Self` is laid out like `[T; $n]`, so `n` coordinates are `$n * n` fields.
+                unsafe {
+                    slice::from_raw_parts_mut(
+                        coordinates.as_mut_ptr().cast(),
+                        coordinates.len() * $n,
+                    )
+                }
+            }
+
+            /// Views `fields`, one coordinate after the other, as coordinates.
+            ///
+            #[doc = concat!("Returns `None` if the length of `fields` isn't a multiple of ", $n, ".")]
+            pub fn from_flat_slice(fields: &[T]) -> Option<&[Self]> {
+                if fields.len() % $n != 0 {
+                    return None;
+                }
+                // SAFETY: `Self` is laid out and aligned like `[T; $n]`; the length was checked.
+                Some(unsafe { slice::from_raw_parts(fields.as_ptr().cast(), fields.len() / $n) })
+            }
+
+            /// Mutably views `fields`, one coordinate after the other, as coordinates.
+            ///
+            #[doc = concat!("Returns `None` if the length of `fields` isn't a multiple of ", $n, ".")]
+            pub fn from_flat_mut_slice(fields: &mut [T]) -> Option<&mut [Self]> {
+                if fields.len() % $n != 0 {
+                    return None;
+                }
+                // SAFETY: `Self` is laid out and aligned like `[T; $n]`; the length was checked.
+                Some(unsafe {
+                    slice::from_raw_parts_mut(fields.as_mut_ptr().cast(), fields.len() / $n)
+                })
+            }
+        }
+
+        impl<T> From<[T; $n]> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn from([$($field),+]: [T; $n]) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl<T> From<$name<T>> for [T; $n]
+        where
+            T: CoordinateField + $sign,
+        {
+            fn from(coordinate: $name<T>) -> Self {
+                [$(coordinate.$field),+]
+            }
+        }
+
+        impl<T> From<($(field_type!($field)),+)> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn from(($($field),+): ($(field_type!($field)),+)) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl<T> From<$name<T>> for ($(field_type!($field)),+)
+        where
+            T: CoordinateField + $sign,
+        {
+            fn from(coordinate: $name<T>) -> Self {
+                ($(coordinate.$field),+)
+            }
+        }
+
+        // SAFETY: `#[repr(C)]` with fields of the same `Zeroable` type.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T> bytemuck::Zeroable for $name<T> where
+            T: bytemuck::Zeroable
+                + CoordinateField + $sign
+        {
+        }
+
+        // SAFETY: `#[repr(C)]` with fields of the same `Pod` type can't contain padding.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl<T> bytemuck::Pod for $name<T> where
+            T: bytemuck::Pod
+                + CoordinateField + $sign
+        {
+        }
+    };
+}
+
+impl_layout!(SignedCoordinate, Signed, 3, [x, y, z]);
+impl_layout!(UnsignedCoordinate, Unsigned, 3, [x, y, z]);
+impl_layout!(SignedCoordinate2, Signed, 2, [x, y]);
+impl_layout!(UnsignedCoordinate2, Unsigned, 2, [x, y]);
@@ -0,0 +1,347 @@
+use core::ops::{Shl, ShlAssign, Shr, ShrAssign};
+
+use num::{
+    traits::{CheckedShl, CheckedShr},
+    Signed, ToPrimitive, Unsigned,
+};
+
+use super::{
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::CoordinateField,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Component-wise math on coordinates, required by [`CoordinateTrait`](super::CoordinateTrait)
+/// and [`Coordinate2Trait`](super::Coordinate2Trait).
+///
+/// Like the arithmetic operators, every method panics if the result overflows. The cross product
+/// only exists for 3D coordinates, see [`Coordinate3Math`].
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let a = Coordinate::new(1, -2, 3);
+/// let b = Coordinate::new(-4, 5, -6);
+///
+/// assert_eq!(a.min(b), Coordinate::new(-4, -2, -6));
+/// assert_eq!(b.abs(), Coordinate::new(4, 5, 6));
+/// assert_eq!(a.dot(b), -32);
+/// assert_eq!(a.cross(b), Coordinate::new(-3, -6, -3));
+/// assert_eq!(a << 2, Coordinate::new(4, -8, 12));
+/// ```
+pub trait CoordinateMath: Sized {
+    /// Type of every axis.
+    type FieldType;
+
+    /// Returns the smaller value of each axis.
+    fn min(self, other: Self) -> Self;
+
+    /// Returns the larger value of each axis.
+    fn max(self, other: Self) -> Self;
+
+    /// Restricts each axis to the range given by the same axis of `lo` and `hi`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo` is larger than `hi` on any axis.
+    fn clamp(self, lo: Self, hi: Self) -> Self;
+
+    /// Returns the absolute value of each axis.
+    fn abs(self) -> Self;
+
+    /// Returns `-1`, `0` or `1` for each axis, depending on its sign.
+    fn signum(self) -> Self;
+
+    /// Raises each axis to the power of `exp`.
+    fn pow(self, exp: u32) -> Self;
+
+    /// Returns the smallest value of all axes.
+    fn min_element(self) -> Self::FieldType;
+
+    /// Returns the largest value of all axes.
+    fn max_element(self) -> Self::FieldType;
+
+    /// Returns the sum of all axes.
+    fn sum(self) -> Self::FieldType;
+
+    /// Returns the product of all axes.
+    fn product(self) -> Self::FieldType;
+
+    /// Returns the dot product.
+    fn dot(self, other: Self) -> Self::FieldType;
+}
+
+/// 3D-only math, required by [`CoordinateTrait`](super::CoordinateTrait).
+pub trait Coordinate3Math: CoordinateMath {
+    /// Returns the cross product.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    fn cross(self, other: Self) -> Self;
+}
+
+fn checked<T>(result: Option<T>) -> T {
+    result.expect("attempt to compute a coordinate with overflow")
+}
+
+/// Shifts `n` to the left, failing if any set bit is shifted out or the sign changes.
+fn checked_shl<T>(n: T, amount: u32) -> Option<T>
+where
+    T: CoordinateField + CheckedShl + CheckedShr,
+{
+    let shifted = n.checked_shl(amount)?;
+    let is_negative = |n: T| n < T::zero();
+    (shifted.checked_shr(amount)? == n && is_negative(shifted) == is_negative(n)).then_some(shifted)
+}
+
+fn shift_amount<T: ToPrimitive>(amount: T) -> u32 {
+    checked(amount.to_u32())
+}
+
+/// Implements [`CoordinateMath`] and the shift operators for `$name`, which has the fields
+/// `$fields`.
+macro_rules! impl_math {
+    ($name:ident, $sign:ident, [$($field:ident),+]) => {
+        /// Component-wise `min`, `max` and `clamp` take precedence over the ones of `Ord`, which
+        /// compare whole coordinates. Use `Ord::min` and friends explicitly for those.
+        impl<T> $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            /// Returns the smaller value of each axis.
+            pub fn min(self, other: Self) -> Self {
+                CoordinateMath::min(self, other)
+            }
+
+            /// Returns the larger value of each axis.
+            pub fn max(self, other: Self) -> Self {
+                CoordinateMath::max(self, other)
+            }
+
+            /// Restricts each axis to the range given by the same axis of `lo` and `hi`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lo` is larger than `hi` on any axis.
+            pub fn clamp(self, lo: Self, hi: Self) -> Self {
+                CoordinateMath::clamp(self, lo, hi)
+            }
+        }
+
+        impl<T> CoordinateMath for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            type FieldType = T;
+
+            fn min(self, other: Self) -> Self {
+                Self {
+                    $($field: self.$field.min(other.$field),)+
+                }
+            }
+
+            fn max(self, other: Self) -> Self {
+                Self {
+                    $($field: self.$field.max(other.$field),)+
+                }
+            }
+
+            fn clamp(self, lo: Self, hi: Self) -> Self {
+                Self {
+                    $($field: self.$field.clamp(lo.$field, hi.$field),)+
+                }
+            }
+
+            fn abs(self) -> Self {
+                let abs = |n: T| match n < T::zero() {
+                    true => checked(T::zero().checked_sub(&n)),
+                    false => n,
+                };
+                Self {
+                    $($field: abs(self.$field),)+
+                }
+            }
+
+            fn signum(self) -> Self {
+                let signum = |n: T| match n.cmp(&T::zero()) {
+                    core::cmp::Ordering::Less => T::zero() - T::one(),
+                    core::cmp::Ordering::Equal => T::zero(),
+                    core::cmp::Ordering::Greater => T::one(),
+                };
+                Self {
+                    $($field: signum(self.$field),)+
+                }
+            }
+
+            fn pow(self, exp: u32) -> Self {
+                let exp = checked(usize::try_from(exp).ok());
+                let pow = |n: T| checked(num::checked_pow(n, exp));
+                Self {
+                    $($field: pow(self.$field),)+
+                }
+            }
+
+            fn min_element(self) -> T {
+                [$(self.$field),+].into_iter().fold(T::MAX, Ord::min)
+            }
+
+            fn max_element(self) -> T {
+                [$(self.$field),+].into_iter().fold(T::MIN, Ord::max)
+            }
+
+            fn sum(self) -> T {
+                checked(
+                    [$(self.$field),+]
+                        .into_iter()
+                        .try_fold(T::zero(), |sum, n| sum.checked_add(&n)),
+                )
+            }
+
+            fn product(self) -> T {
+                checked(
+                    [$(self.$field),+]
+                        .into_iter()
+                        .try_fold(T::one(), |product, n| product.checked_mul(&n)),
+                )
+            }
+
+            fn dot(self, other: Self) -> T {
+                let mul = |a: T, b: T| checked(a.checked_mul(&b));
+                Self {
+                    $($field: mul(self.$field, other.$field),)+
+                }
+                .sum()
+            }
+        }
+
+        impl_math!(@shifts $name, $sign, [$($field),+]);
+    };
+    (@shifts $name:ident, $sign:ident, [$($field:ident),+]) => {
+        impl<T> Shl<u32> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            type Output = Self;
+
+            fn shl(self, rhs: u32) -> Self::Output {
+                let shl = |n: T| checked(checked_shl(n, rhs));
+                Self {
+                    $($field: shl(self.$field),)+
+                }
+            }
+        }
+
+        impl<T> ShlAssign<u32> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl<T> Shl for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            type Output = Self;
+
+            fn shl(self, rhs: Self) -> Self::Output {
+                let shl = |n: T, amount: T| checked(checked_shl(n, shift_amount(amount)));
+                Self {
+                    $($field: shl(self.$field, rhs.$field),)+
+                }
+            }
+        }
+
+        impl<T> ShlAssign for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn shl_assign(&mut self, rhs: Self) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl<T> Shr<u32> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            type Output = Self;
+
+            fn shr(self, rhs: u32) -> Self::Output {
+                let shr = |n: T| checked(n.checked_shr(rhs));
+                Self {
+                    $($field: shr(self.$field),)+
+                }
+            }
+        }
+
+        impl<T> ShrAssign<u32> for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = *self >> rhs;
+            }
+        }
+
+        impl<T> Shr for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            type Output = Self;
+
+            fn shr(self, rhs: Self) -> Self::Output {
+                let shr = |n: T, amount: T| checked(n.checked_shr(shift_amount(amount)));
+                Self {
+                    $($field: shr(self.$field, rhs.$field),)+
+                }
+            }
+        }
+
+        impl<T> ShrAssign for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn shr_assign(&mut self, rhs: Self) {
+                *self = *self >> rhs;
+            }
+        }
+    };
+}
+
+impl_math!(SignedCoordinate, Signed, [x, y, z]);
+impl_math!(UnsignedCoordinate, Unsigned, [x, y, z]);
+impl_math!(SignedCoordinate2, Signed, [x, y]);
+impl_math!(UnsignedCoordinate2, Unsigned, [x, y]);
+
+/// Implements [`Coordinate3Math`] for `$name`.
+macro_rules! impl_cross {
+    ($name:ident, $sign:ident) => {
+        impl<T> Coordinate3Math for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn cross(self, other: Self) -> Self {
+                let determinant = |a: T, b: T, c: T, d: T| {
+                    let ad = checked(a.checked_mul(&d));
+                    let bc = checked(b.checked_mul(&c));
+                    checked(ad.checked_sub(&bc))
+                };
+                Self {
+                    x: determinant(self.y, self.z, other.y, other.z),
+                    y: determinant(self.z, self.x, other.z, other.x),
+                    z: determinant(self.x, self.y, other.x, other.y),
+                }
+            }
+        }
+    };
+}
+
+impl_cross!(SignedCoordinate, Signed);
+impl_cross!(UnsignedCoordinate, Unsigned);
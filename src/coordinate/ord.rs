@@ -0,0 +1,41 @@
+use core::cmp::Ordering;
+
+use num::{Signed, Unsigned};
+
+use super::{
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::CoordinateField,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Implements the ordering for `$name`, comparing the last field first.
+macro_rules! impl_ord {
+    ($name:ident, $sign:ident, [$($field:ident),+]) => {
+        impl<T> PartialOrd for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        /// Compares the last axis first, matching the iteration order of `Region` and
+        /// `Rectangle`.
+        impl<T> Ord for $name<T>
+        where
+            T: CoordinateField + $sign,
+        {
+            fn cmp(&self, other: &Self) -> Ordering {
+                let fields = |coordinate: &Self| [$(coordinate.$field),+];
+                fields(self).iter().rev().cmp(fields(other).iter().rev())
+            }
+        }
+    };
+}
+
+impl_ord!(SignedCoordinate, Signed, [x, y, z]);
+impl_ord!(UnsignedCoordinate, Unsigned, [x, y, z]);
+impl_ord!(SignedCoordinate2, Signed, [x, y]);
+impl_ord!(UnsignedCoordinate2, Unsigned, [x, y]);
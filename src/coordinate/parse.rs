@@ -0,0 +1,268 @@
+use core::{array, str::FromStr};
+
+use num::{Signed, Unsigned};
+use thiserror::Error;
+
+use super::{
+    axis::Axis,
+    coordinate2::{SignedCoordinate2, UnsignedCoordinate2},
+    coordinate_trait::CoordinateField,
+    signed_coordinate::SignedCoordinate,
+    unsigned_coordinate::UnsignedCoordinate,
+};
+
+/// Error returned when parsing a coordinate fails.
+///
+/// Positions are byte offsets into the parsed string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ParseCoordinateError {
+    #[error("Expected a coordinate, found an empty string.")]
+    Empty,
+    #[error("Unexpected {found:?} at position {position}.")]
+    UnexpectedCharacter { position: usize, found: char },
+    #[error("Expected {expected:?} at position {position}, found the end of the input.")]
+    UnexpectedEnd { position: usize, expected: char },
+    #[error("Missing the {axis} axis at position {position}.")]
+    MissingAxis { axis: Axis, position: usize },
+    #[error("The {axis} axis is given twice, the second time at position {position}.")]
+    DuplicateAxis { axis: Axis, position: usize },
+    #[error("The {axis} axis at position {position} is out of range.")]
+    OutOfRange { axis: Axis, position: usize },
+}
+
+impl ParseCoordinateError {
+    /// Shifts all positions by `offset`, for errors of a coordinate embedded in a larger string.
+    pub(crate) fn offset(self, offset: usize) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::UnexpectedCharacter { position, found } => Self::UnexpectedCharacter {
+                position: position + offset,
+                found,
+            },
+            Self::UnexpectedEnd { position, expected } => Self::UnexpectedEnd {
+                position: position + offset,
+                expected,
+            },
+            Self::MissingAxis { axis, position } => Self::MissingAxis {
+                axis,
+                position: position + offset,
+            },
+            Self::DuplicateAxis { axis, position } => Self::DuplicateAxis {
+                axis,
+                position: position + offset,
+            },
+            Self::OutOfRange { axis, position } => Self::OutOfRange {
+                axis,
+                position: position + offset,
+            },
+        }
+    }
+}
+
+struct Scanner<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn advance(&mut self) {
+        if let Some(c) = self.peek() {
+            self.position += c.len_utf8();
+        }
+    }
+
+    /// Skips whitespace, returning `true` if there was any.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.position;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.advance();
+        }
+        self.position != start
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.advance();
+        }
+        found
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let found = self.input[self.position..].starts_with(s);
+        if found {
+            self.position += s.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseCoordinateError> {
+        match self.peek() {
+            Some(found) if found == c => {
+                self.advance();
+                Ok(())
+            }
+            Some(found) => Err(self.unexpected(found)),
+            None => Err(ParseCoordinateError::UnexpectedEnd {
+                position: self.position,
+                expected: c,
+            }),
+        }
+    }
+
+    fn unexpected(&self, found: char) -> ParseCoordinateError {
+        ParseCoordinateError::UnexpectedCharacter {
+            position: self.position,
+            found,
+        }
+    }
+
+    /// Skips the whitespace and/or single comma between two axes.
+    fn separator(&mut self) -> Result<(), ParseCoordinateError> {
+        let whitespace = self.skip_whitespace();
+        if self.eat(',') {
+            self.skip_whitespace();
+        } else if !whitespace {
+            if let Some(found) = self.peek() {
+                return Err(self.unexpected(found));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a label like `x=` of one of the first `axes` axes, if there is one.
+    fn label(&mut self, axes: usize) -> Result<Option<Axis>, ParseCoordinateError> {
+        let axis = match self.peek() {
+            Some('x' | 'X') => Axis::X,
+            Some('y' | 'Y') => Axis::Y,
+            Some('z' | 'Z') => Axis::Z,
+            _ => return Ok(None),
+        };
+        if axis.index() >= axes {
+            return Err(self.unexpected(self.peek().expect("a label was found")));
+        }
+        self.advance();
+        self.skip_whitespace();
+        self.expect('=')?;
+        self.skip_whitespace();
+        Ok(Some(axis))
+    }
+
+    fn number<T: FromStr>(&mut self, axis: Axis) -> Result<T, ParseCoordinateError> {
+        let start = self.position;
+        if matches!(self.peek(), Some('+' | '-')) {
+            self.advance();
+        }
+        let digits = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.advance();
+        }
+        if self.position == digits {
+            return Err(match self.peek() {
+                Some(found) => self.unexpected(found),
+                None => ParseCoordinateError::MissingAxis {
+                    axis,
+                    position: self.position,
+                },
+            });
+        }
+        // The token is a well-formed integer, so it can only fail by not fitting into `T`.
+        self.input[start..self.position]
+            .parse()
+            .map_err(|_| ParseCoordinateError::OutOfRange {
+                axis,
+                position: start,
+            })
+    }
+}
+
+/// Parses the `N` fields of a coordinate in any of the supported formats.
+fn parse_fields<T: FromStr, const N: usize>(
+    input: &str,
+    name: &str,
+) -> Result<[T; N], ParseCoordinateError> {
+    let mut scanner = Scanner { input, position: 0 };
+    scanner.skip_whitespace();
+
+    let close = match scanner.peek() {
+        None => return Err(ParseCoordinateError::Empty),
+        Some('(') => {
+            scanner.advance();
+            scanner.skip_whitespace();
+            if scanner.eat_str(name) {
+                scanner.skip_whitespace();
+                scanner.expect(':')?;
+            }
+            Some(')')
+        }
+        Some('[') => {
+            scanner.advance();
+            Some(']')
+        }
+        Some(_) => None,
+    };
+    scanner.skip_whitespace();
+
+    let mut fields: [Option<T>; N] = array::from_fn(|_| None);
+    let mut labelled = false;
+    for index in 0..N {
+        if index > 0 {
+            scanner.separator()?;
+        }
+        let position = scanner.position;
+        let label = scanner.label(N)?;
+        if index == 0 {
+            labelled = label.is_some();
+        } else if label.is_some() != labelled {
+            scanner.position = position;
+            if let Some(found) = scanner.peek() {
+                return Err(scanner.unexpected(found));
+            }
+        }
+
+        let axis = label.unwrap_or(Axis::ALL[index]);
+        if fields[axis.index()].is_some() {
+            return Err(ParseCoordinateError::DuplicateAxis { axis, position });
+        }
+        fields[axis.index()] = Some(scanner.number(axis)?);
+    }
+
+    scanner.skip_whitespace();
+    if let Some(close) = close {
+        scanner.expect(close)?;
+        scanner.skip_whitespace();
+    }
+    if let Some(found) = scanner.peek() {
+        return Err(scanner.unexpected(found));
+    }
+
+    Ok(fields.map(|field| field.expect("every axis is parsed exactly once")))
+}
+
+/// Implements `FromStr` for `$name`.
+macro_rules! impl_from_str {
+    ($name:ident, $sign:ident, [$($field:ident),+]) => {
+        /// Parses the `Display` format as well as the axes separated by whitespace and/or
+        /// commas, optionally in square brackets or labelled, e.g. `1 2 3`, `1,2,3`, `[1, 2, 3]`
+        /// and `x=1 y=2 z=3` for 3D coordinates.
+        impl<T> FromStr for $name<T>
+        where
+            T: CoordinateField + $sign + FromStr,
+        {
+            type Err = ParseCoordinateError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_fields(s, stringify!($name)).map(|[$($field),+]| Self { $($field),+ })
+            }
+        }
+    };
+}
+
+impl_from_str!(SignedCoordinate, Signed, [x, y, z]);
+impl_from_str!(UnsignedCoordinate, Unsigned, [x, y, z]);
+impl_from_str!(SignedCoordinate2, Signed, [x, y]);
+impl_from_str!(UnsignedCoordinate2, Unsigned, [x, y]);
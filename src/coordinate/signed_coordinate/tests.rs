@@ -1,6 +1,9 @@
 use quickcheck::quickcheck;
 
 use super::*;
+use crate::coordinate::{
+    Axis, Coordinate3Math, CoordinateMath, CoordinateTrait, UnsignedCoordinate,
+};
 
 quickcheck! {
     fn new(x: isize, y: isize, z: isize) -> bool {
@@ -10,7 +13,9 @@ quickcheck! {
     }
 }
 
+#[cfg(feature = "quickcheck")]
 quickcheck! {
+    #[allow(clippy::absurd_extreme_comparisons)]
     fn arbitrary(coord: SignedCoordinate<isize>) -> bool {
         let x_fine = coord.x >= SignedCoordinate::MIN.x && coord.x <= SignedCoordinate::MAX.x;
         let y_fine = coord.y >= SignedCoordinate::MIN.y && coord.y <= SignedCoordinate::MAX.y;
@@ -264,6 +269,12 @@ fn neg() {
     assert_eq!(result, expected);
 }
 
+#[test]
+#[should_panic]
+fn neg_overflow() {
+    let _ = -SignedCoordinate::new(i32::MIN, 0, 0);
+}
+
 #[test]
 fn not() {
     let result = !SignedCoordinate::<isize>::MAX;
@@ -378,3 +389,348 @@ fn bit_xor_assign_single() {
     let expected = SignedCoordinate::new(0b01100110, 0b11000011, 0b01100000);
     assert_eq!(coord_a, expected);
 }
+
+#[test]
+fn array_conversions() {
+    let coord = SignedCoordinate::new(1, -2, 3);
+    assert_eq!(SignedCoordinate::from([1, -2, 3]), coord);
+    assert_eq!(SignedCoordinate::from((1, -2, 3)), coord);
+    assert_eq!(<[i32; 3]>::from(coord), [1, -2, 3]);
+    assert_eq!(<(i32, i32, i32)>::from(coord), (1, -2, 3));
+    assert_eq!(coord.as_slice(), &[1, -2, 3]);
+}
+
+#[test]
+fn as_mut_array() {
+    let mut coord = SignedCoordinate::new(1, -2, 3);
+    coord.as_mut_array()[1] = 5;
+    assert_eq!(coord, SignedCoordinate::new(1, 5, 3));
+}
+
+#[test]
+fn flat_slices() {
+    let mut coords = [
+        SignedCoordinate::new(1, 2, 3),
+        SignedCoordinate::new(-4, -5, -6),
+    ];
+    assert_eq!(
+        SignedCoordinate::flatten_slice(&coords),
+        &[1, 2, 3, -4, -5, -6]
+    );
+
+    SignedCoordinate::flatten_mut_slice(&mut coords)[5] = 0;
+    assert_eq!(coords[1].z, 0);
+
+    let fields = [7, 8, 9, 10, 11, 12];
+    let coords = SignedCoordinate::from_flat_slice(&fields).unwrap();
+    assert_eq!(
+        coords,
+        &[
+            SignedCoordinate::new(7, 8, 9),
+            SignedCoordinate::new(10, 11, 12)
+        ]
+    );
+    assert_eq!(SignedCoordinate::from_flat_slice(&fields[..4]), None);
+}
+
+#[test]
+fn component_wise_math() {
+    let a = SignedCoordinate::new(-3, 0, 7);
+    let b = SignedCoordinate::new(2, -1, 7);
+    assert_eq!(a.min(b), SignedCoordinate::new(-3, -1, 7));
+    assert_eq!(a.max(b), SignedCoordinate::new(2, 0, 7));
+    assert_eq!(
+        a.clamp(SignedCoordinate::splat(-1), SignedCoordinate::splat(5)),
+        SignedCoordinate::new(-1, 0, 5)
+    );
+    assert_eq!(a.abs(), SignedCoordinate::new(3, 0, 7));
+    assert_eq!(a.signum(), SignedCoordinate::new(-1, 0, 1));
+    assert_eq!(a.pow(2), SignedCoordinate::new(9, 0, 49));
+    assert_eq!(a.min_element(), -3);
+    assert_eq!(a.max_element(), 7);
+    assert_eq!(b.sum(), 8);
+    assert_eq!(b.product(), -14);
+}
+
+#[test]
+fn math_through_coordinate_trait() {
+    fn extent<C>(a: C, b: C) -> C
+    where
+        C: CoordinateTrait + Copy + core::ops::Sub<Output = C>,
+    {
+        (a.max(b) - a.min(b)).abs()
+    }
+
+    let a = SignedCoordinate::new(1, -2, 3);
+    let b = SignedCoordinate::new(-4, 5, 3);
+    assert_eq!(extent(a, b), SignedCoordinate::new(5, 7, 0));
+}
+
+#[test]
+fn dot_cross() {
+    let x = SignedCoordinate::new(1, 0, 0);
+    let y = SignedCoordinate::new(0, 1, 0);
+    assert_eq!(x.dot(y), 0);
+    assert_eq!(x.cross(y), SignedCoordinate::new(0, 0, 1));
+    assert_eq!(y.cross(x), SignedCoordinate::new(0, 0, -1));
+}
+
+#[test]
+#[should_panic]
+fn abs_overflow() {
+    let _ = SignedCoordinate::new(i32::MIN, 0, 0).abs();
+}
+
+#[test]
+#[should_panic]
+fn dot_overflow() {
+    let _ = SignedCoordinate::splat(i32::MAX).dot(SignedCoordinate::splat(2));
+}
+
+#[test]
+fn shifts() {
+    let mut coord = SignedCoordinate::new(1, -4, 8);
+    assert_eq!(coord << 1, SignedCoordinate::new(2, -8, 16));
+    assert_eq!(
+        coord >> SignedCoordinate::new(0, 1, 2),
+        SignedCoordinate::new(1, -2, 2)
+    );
+    coord <<= SignedCoordinate::new(1, 0, 1);
+    coord >>= 1;
+    assert_eq!(coord, SignedCoordinate::new(1, -2, 8));
+}
+
+#[test]
+fn generic_shifts_and_cross() {
+    fn shift_and_cross<C: CoordinateTrait + Copy>(a: C, b: C) -> C {
+        ((a << 2) >> b).cross(b)
+    }
+
+    let result = shift_and_cross(
+        SignedCoordinate::new(1, -4, 8),
+        SignedCoordinate::new(1, 0, 2),
+    );
+    assert_eq!(result, SignedCoordinate::new(-32, 4, 16));
+}
+
+#[test]
+#[should_panic]
+fn shift_overflow() {
+    let _ = SignedCoordinate::<i32>::splat(1) << 32;
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn shift_loses_bits() {
+    let _ = SignedCoordinate::<i32>::MAX << 1;
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn shift_changes_sign() {
+    let _ = SignedCoordinate::<i32>::splat(1) << SignedCoordinate::splat(31);
+}
+
+#[test]
+fn shift_negative() {
+    assert_eq!(
+        SignedCoordinate::<i8>::splat(-64) << 1,
+        SignedCoordinate::splat(-128)
+    );
+}
+
+#[test]
+#[should_panic]
+fn negative_shift() {
+    let _ = SignedCoordinate::<i32>::splat(1) >> SignedCoordinate::new(0, -1, 0);
+}
+
+#[test]
+fn map() {
+    let coord = SignedCoordinate::new(1i32, -2, 3);
+    assert_eq!(coord.map(|n| n * 2), SignedCoordinate::new(2, -4, 6));
+    assert_eq!(coord.map(i64::from), SignedCoordinate::new(1i64, -2, 3));
+    assert_eq!(
+        coord.try_map(i8::try_from),
+        Ok(SignedCoordinate::new(1i8, -2, 3))
+    );
+    assert!(coord.with_y(1000).try_map(i8::try_from).is_err());
+}
+
+#[test]
+fn zip_fold() {
+    let a = SignedCoordinate::new(1, 2, 3);
+    let b = SignedCoordinate::new(4, -5, 6);
+    assert_eq!(
+        a.zip_with(b, |a, b| a.max(b)),
+        SignedCoordinate::new(4, 2, 6)
+    );
+    assert_eq!(a.fold(0, |sum, n| sum * 10 + n), 123);
+}
+
+#[test]
+fn components() {
+    let mut coord = SignedCoordinate::new(1, 2, 3);
+    coord.iter_mut().for_each(|n| *n = -*n);
+    assert_eq!(coord.iter().copied().collect::<Vec<_>>(), vec![-1, -2, -3]);
+
+    coord[Axis::Y] = 7;
+    assert_eq!(coord[Axis::X], -1);
+    assert_eq!(coord, SignedCoordinate::new(-1, 7, -3));
+}
+
+#[test]
+fn swizzles() {
+    let coord = SignedCoordinate::new(1, 2, 3);
+    assert_eq!(coord.xzy(), SignedCoordinate::new(1, 3, 2));
+    assert_eq!(coord.yxz(), SignedCoordinate::new(2, 1, 3));
+    assert_eq!(coord.yzx(), SignedCoordinate::new(2, 3, 1));
+    assert_eq!(coord.zxy(), SignedCoordinate::new(3, 1, 2));
+    assert_eq!(coord.zyx(), SignedCoordinate::new(3, 2, 1));
+    assert_eq!(coord.with_x(0).with_z(0), SignedCoordinate::new(0, 2, 0));
+}
+
+#[test]
+fn widening() {
+    let coord = SignedCoordinate::new(i32::MIN, 0, i32::MAX);
+    let wide = SignedCoordinate::<i64>::from(coord);
+    assert_eq!(
+        wide,
+        SignedCoordinate::new(i64::from(i32::MIN), 0, i64::from(i32::MAX))
+    );
+}
+
+#[test]
+fn narrowing() {
+    let coord = SignedCoordinate::new(1i64, -2, 3);
+    assert_eq!(
+        SignedCoordinate::<i8>::try_from(coord),
+        Ok(SignedCoordinate::new(1, -2, 3))
+    );
+
+    let error = UnsignedCoordinate::<u32>::try_from(coord).unwrap_err();
+    assert_eq!(error.axis, Axis::Y);
+
+    let coord = SignedCoordinate::new(0i64, 0, i64::MAX);
+    let error = SignedCoordinate::<i32>::try_from(coord).unwrap_err();
+    assert_eq!(error.axis, Axis::Z);
+}
+
+#[test]
+fn casts() {
+    let coord = SignedCoordinate::new(-129i32, 128, 5);
+    assert_eq!(
+        coord.cast_saturating::<SignedCoordinate<i8>>(),
+        SignedCoordinate::new(i8::MIN, i8::MAX, 5)
+    );
+    assert_eq!(
+        coord.cast_wrapping::<SignedCoordinate<i8>>(),
+        SignedCoordinate::new(127, -128, 5)
+    );
+}
+
+#[test]
+fn ordering() {
+    let mut coords = vec![
+        SignedCoordinate::new(1, 0, 0),
+        SignedCoordinate::new(0, 0, 1),
+        SignedCoordinate::new(0, 1, 0),
+        SignedCoordinate::new(0, 0, 0),
+        SignedCoordinate::new(0, 1, 0),
+    ];
+    coords.sort();
+    coords.dedup();
+    assert_eq!(
+        coords,
+        vec![
+            SignedCoordinate::new(0, 0, 0),
+            SignedCoordinate::new(1, 0, 0),
+            SignedCoordinate::new(0, 1, 0),
+            SignedCoordinate::new(0, 0, 1),
+        ]
+    );
+}
+
+#[test]
+fn component_wise_min_takes_precedence() {
+    let a = SignedCoordinate::new(0, 0, 1);
+    let b = SignedCoordinate::new(1, 1, 0);
+    assert_eq!(a.min(b), SignedCoordinate::new(0, 0, 0));
+    assert_eq!(Ord::min(a, b), b);
+}
+
+#[test]
+fn parse_display() {
+    let coord = SignedCoordinate::new(-1i64, 2, i64::MIN);
+    assert_eq!(coord.to_string().parse(), Ok(coord));
+}
+
+#[test]
+fn parse_lenient() {
+    let expected = Ok(SignedCoordinate::new(1, -2, 3));
+    assert_eq!("1 -2 3".parse(), expected);
+    assert_eq!("1,-2,3".parse(), expected);
+    assert_eq!(" [1, -2, +3] ".parse(), expected);
+    assert_eq!("x=1 y=-2 z=3".parse(), expected);
+    assert_eq!("z = 3, x = 1, y = -2".parse(), expected);
+    assert_eq!("(1, -2, 3)".parse(), expected);
+}
+
+#[test]
+fn parse_errors() {
+    use crate::coordinate::ParseCoordinateError::*;
+
+    let parse = |s: &str| s.parse::<SignedCoordinate<i8>>().unwrap_err();
+    let unexpected = |position, found| UnexpectedCharacter { position, found };
+    let missing = |axis, position| MissingAxis { axis, position };
+    let duplicate = |axis, position| DuplicateAxis { axis, position };
+    let out_of_range = |axis, position| OutOfRange { axis, position };
+
+    assert_eq!(parse("  "), Empty);
+    assert_eq!(parse("1 2"), missing(Axis::Z, 3));
+    assert_eq!(parse("1 2 300"), out_of_range(Axis::Z, 4));
+    assert_eq!(parse("x=1 x=2 z=3"), duplicate(Axis::X, 4));
+    assert_eq!(parse("1 a 3"), unexpected(2, 'a'));
+    assert_eq!(parse("1 2 3 4"), unexpected(6, '4'));
+    assert_eq!(parse("1 y=2 3"), unexpected(2, 'y'));
+    assert_eq!(parse("x=1 2 3"), unexpected(4, '2'));
+    assert_eq!(parse("(UnsignedCoordinate: 1, 2, 3)"), unexpected(1, 'U'));
+    assert_eq!(
+        parse("[1 2 3"),
+        UnexpectedEnd {
+            position: 6,
+            expected: ']'
+        }
+    );
+}
+
+#[test]
+fn format_with() {
+    use core::num::NonZero;
+
+    use crate::coordinate::CoordinateFormat;
+
+    let coord = SignedCoordinate::new(-17i8, 0, 100);
+    assert_eq!(
+        coord.format_with(CoordinateFormat::new()).to_string(),
+        coord.to_string()
+    );
+    assert_eq!(
+        format!("{:<3}|", coord.format_with(CoordinateFormat::compact())),
+        "-17 0   100|"
+    );
+
+    // 200 doesn't fit into an i8, so the chunk size is ignored.
+    let chunks = |size| CoordinateFormat::json().chunk_size(NonZero::new(size).unwrap());
+    assert_eq!(
+        coord.format_with(chunks(200)).to_string(),
+        r#"{"x":-17,"y":0,"z":100}"#
+    );
+    assert_eq!(
+        coord.format_with(chunks(16)).to_string(),
+        concat!(
+            r#"{"x":{"chunk":-2,"local":15},"y":{"chunk":0,"local":0},"#,
+            r#""z":{"chunk":6,"local":4}}"#
+        )
+    );
+}
@@ -1,35 +1,8 @@
-use std::ops::{BitAnd, BitOr, BitXor, Not};
+use num::Unsigned;
 
-use argentum_game_coordinate_system_macros::Coordinate;
-use min_max_traits::{Max, Min};
-use num::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Integer, Unsigned};
-use quickcheck::Arbitrary;
+use super::generate::coordinate_type;
 
-use super::coordinate_trait::CoordinateTrait;
-
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
 
-#[derive(Coordinate, Clone, Debug, PartialEq)]
-pub struct UnsignedCoordinate<T>
-where
-    T: Integer
-        + Unsigned
-        + Copy
-        + CheckedAdd
-        + CheckedSub
-        + CheckedMul
-        + CheckedDiv
-        + Display
-        + Max
-        + Min
-        + Arbitrary
-        + BitAnd<Output = T>
-        + BitOr<Output = T>
-        + BitXor<Output = T>
-        + Not<Output = T>,
-{
-    pub x: T,
-    pub y: T,
-    pub z: T,
-}
+coordinate_type!(UnsignedCoordinate, Unsigned, [x, y, z]);
@@ -1,6 +1,7 @@
 use quickcheck::quickcheck;
 
 use super::*;
+use crate::coordinate::{Axis, Coordinate3Math, CoordinateMath, CoordinateTrait, SignedCoordinate};
 
 quickcheck! {
     fn new(x: usize, y: usize, z: usize) -> bool {
@@ -10,7 +11,9 @@ quickcheck! {
     }
 }
 
+#[cfg(feature = "quickcheck")]
 quickcheck! {
+    #[allow(clippy::absurd_extreme_comparisons)]
     fn arbitrary(coord: UnsignedCoordinate<usize>) -> bool {
         let x_fine = coord.x >= UnsignedCoordinate::MIN.x && coord.x <= UnsignedCoordinate::MAX.x;
         let y_fine = coord.y >= UnsignedCoordinate::MIN.y && coord.y <= UnsignedCoordinate::MAX.y;
@@ -151,6 +154,7 @@ fn mul() {
 }
 
 #[test]
+#[allow(clippy::erasing_op)]
 fn mul_single() {
     let result = UnsignedCoordinate::<usize>::new(5, 2, 4) * 0;
     let expected = UnsignedCoordinate::new(0, 0, 0);
@@ -371,3 +375,117 @@ fn bit_xor_assign_single() {
     let expected = UnsignedCoordinate::new(0b01100110, 0b11000011, 0b01100000);
     assert_eq!(coord_a, expected);
 }
+
+#[test]
+fn array_conversions() {
+    let coord = UnsignedCoordinate::new(1, 2, 3);
+    assert_eq!(UnsignedCoordinate::from([1, 2, 3]), coord);
+    assert_eq!(UnsignedCoordinate::from((1, 2, 3)), coord);
+    assert_eq!(<[u32; 3]>::from(coord), [1, 2, 3]);
+    assert_eq!(<(u32, u32, u32)>::from(coord), (1, 2, 3));
+    assert_eq!(coord.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+fn flat_slices() {
+    let mut fields = [1u8, 2, 3, 4, 5, 6];
+    let coords = UnsignedCoordinate::from_flat_mut_slice(&mut fields).unwrap();
+    coords[0].x = 9;
+    assert_eq!(
+        UnsignedCoordinate::flatten_slice(coords),
+        &[9, 2, 3, 4, 5, 6]
+    );
+    assert_eq!(UnsignedCoordinate::from_flat_slice(&fields[..2]), None);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_cast() {
+    let coords = [UnsignedCoordinate::new(1u32, 2, 3)];
+    let fields: &[u32] = bytemuck::cast_slice(&coords);
+    assert_eq!(fields, &[1, 2, 3]);
+}
+
+#[test]
+fn component_wise_math() {
+    let coord = UnsignedCoordinate::new(0u32, 3, 5);
+    assert_eq!(coord.abs(), coord);
+    assert_eq!(coord.signum(), UnsignedCoordinate::new(0, 1, 1));
+    assert_eq!(coord.pow(3), UnsignedCoordinate::new(0, 27, 125));
+    assert_eq!(coord.dot(UnsignedCoordinate::splat(2)), 16);
+    assert_eq!(coord >> 1, UnsignedCoordinate::new(0, 1, 2));
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn shift_loses_bits() {
+    let _ = UnsignedCoordinate::<u32>::MAX << 1;
+}
+
+#[test]
+#[should_panic]
+fn cross_underflow() {
+    let _ = UnsignedCoordinate::new(1u32, 0, 0)
+        .cross(UnsignedCoordinate::new(0, 1, 0).cross(UnsignedCoordinate::new(1, 0, 0)));
+}
+
+#[test]
+#[should_panic]
+fn pow_overflow() {
+    let _ = UnsignedCoordinate::new(2u8, 0, 0).pow(8);
+}
+
+#[test]
+fn map() {
+    let coord = UnsignedCoordinate::new(1u8, 2, 3);
+    assert_eq!(coord.map(u32::from), UnsignedCoordinate::new(1u32, 2, 3));
+    assert_eq!(coord[Axis::Z], 3);
+    assert_eq!(coord.zyx().with_y(9), UnsignedCoordinate::new(3, 9, 1));
+}
+
+#[test]
+fn signedness_conversions() {
+    let coord = UnsignedCoordinate::new(1u32, 2, u32::MAX);
+    let signed = SignedCoordinate::<i64>::from(coord);
+    assert_eq!(signed, SignedCoordinate::new(1, 2, i64::from(u32::MAX)));
+
+    let error = SignedCoordinate::<i32>::try_from(coord).unwrap_err();
+    assert_eq!(error.axis, Axis::Z);
+    assert_eq!(
+        coord.cast_saturating::<SignedCoordinate<i32>>(),
+        SignedCoordinate::new(1, 2, i32::MAX)
+    );
+}
+
+#[test]
+fn parse() {
+    let coord = UnsignedCoordinate::new(1u16, 2, 3);
+    assert_eq!(coord.to_string().parse(), Ok(coord));
+    assert_eq!(
+        "1 -2 3".parse::<UnsignedCoordinate<u16>>(),
+        Err(crate::coordinate::ParseCoordinateError::OutOfRange {
+            axis: Axis::Y,
+            position: 2
+        })
+    );
+}
+
+#[test]
+fn format_with() {
+    use crate::coordinate::CoordinateFormat;
+
+    let coord = UnsignedCoordinate::new(10u8, 255, 0);
+    assert_eq!(
+        coord
+            .format_with(CoordinateFormat::new().hex(true))
+            .to_string(),
+        "(UnsignedCoordinate: a, ff, 0)"
+    );
+    assert_eq!(
+        format!(
+            "{:#04}",
+            coord.format_with(CoordinateFormat::compact().hex(true))
+        ),
+        "0x0a 0xff 0x00"
+    );
+}
@@ -0,0 +1,75 @@
+use crate::prelude::*;
+
+/// A distance metric on the integer grid.
+///
+/// Distances are computed in wider integer types, so they never overflow.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum Metric {
+    /// Sum of the distances along each axis.
+    #[default]
+    Manhattan,
+    /// Largest distance along any axis.
+    Chebyshev,
+}
+
+impl Metric {
+    /// Returns the distance between `a` and `b` according to the metric.
+    pub fn distance(self, a: &Coordinate, b: &Coordinate) -> u64 {
+        match self {
+            Metric::Manhattan => manhattan(a, b),
+            Metric::Chebyshev => chebyshev(a, b),
+        }
+    }
+}
+
+/// Returns the Manhattan (taxicab) distance between `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::distance::manhattan;
+///
+/// assert_eq!(manhattan(&Coordinate::new(1, 2, 3), &Coordinate::new(-1, 2, 7)), 6);
+/// assert_eq!(manhattan(&Coordinate::MIN, &Coordinate::MAX), 3 * u64::from(u32::MAX));
+/// ```
+pub fn manhattan(a: &Coordinate, b: &Coordinate) -> u64 {
+    axis_distance(a.x, b.x) + axis_distance(a.y, b.y) + axis_distance(a.z, b.z)
+}
+
+/// Returns the Chebyshev (chessboard) distance between `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::distance::chebyshev;
+///
+/// assert_eq!(chebyshev(&Coordinate::new(1, 2, 3), &Coordinate::new(-1, 2, 7)), 4);
+/// ```
+pub fn chebyshev(a: &Coordinate, b: &Coordinate) -> u64 {
+    axis_distance(a.x, b.x)
+        .max(axis_distance(a.y, b.y))
+        .max(axis_distance(a.z, b.z))
+}
+
+/// Returns the squared Euclidean distance between `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::distance::euclidean_squared;
+///
+/// assert_eq!(euclidean_squared(&Coordinate::new(1, 2, 3), &Coordinate::new(-1, 2, 7)), 20);
+/// ```
+pub fn euclidean_squared(a: &Coordinate, b: &Coordinate) -> u128 {
+    let square = |n: u64| u128::from(n) * u128::from(n);
+    square(axis_distance(a.x, b.x))
+        + square(axis_distance(a.y, b.y))
+        + square(axis_distance(a.z, b.z))
+}
+
+fn axis_distance(a: CoordinateType, b: CoordinateType) -> u64 {
+    (i64::from(a) - i64::from(b)).unsigned_abs()
+}
@@ -0,0 +1,315 @@
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeSet, VecDeque},
+    vec,
+    vec::Vec,
+};
+
+use crate::prelude::*;
+
+/// Settings for flood filling from a starting `Coordinate`.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// #     test().unwrap();
+/// # }
+/// # fn test() -> Option<()> {
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let bounds = Region::new(Coordinate::splat(0), NonZero::new(3)?);
+/// let filled = FloodFill::new(Connectivity::Six)
+///     .bounds(bounds)
+///     .fill(Coordinate::splat(0), |pos| pos.y == 0);
+///
+/// assert_eq!(filled.coordinates.len(), 9);
+/// assert!(!filled.limit_reached);
+/// # Some(())
+/// # }
+/// ```
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct FloodFill {
+    connectivity: Connectivity,
+    bounds: Option<Region>,
+    limit: Option<usize>,
+}
+
+/// Result of a flood fill.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Filled {
+    /// All reached `Coordinate`s in the order they were visited.
+    pub coordinates: Vec<Coordinate>,
+    /// `true` if the fill stopped early because it reached the configured limit.
+    pub limit_reached: bool,
+}
+
+impl FloodFill {
+    /// Creates a new unbounded and unlimited `FloodFill`.
+    pub fn new(connectivity: Connectivity) -> Self {
+        Self {
+            connectivity,
+            bounds: None,
+            limit: None,
+        }
+    }
+
+    /// Restricts the fill to `Coordinate`s within `bounds`.
+    pub fn bounds(mut self, bounds: Region) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Stops the fill after `limit` `Coordinate`s have been reached.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Fills outwards from `start`, visiting every `Coordinate` for which `passable` returns `true`.
+    ///
+    /// `passable` is called at most once per `Coordinate`. If `start` itself isn't passable, nothing is filled.
+    pub fn fill<F>(&self, start: Coordinate, mut passable: F) -> Filled
+    where
+        F: FnMut(&Coordinate) -> bool,
+    {
+        let mut filled = Filled {
+            coordinates: Vec::new(),
+            limit_reached: false,
+        };
+        let mut seen = BTreeSet::new();
+        let mut queue = VecDeque::new();
+
+        seen.insert(start);
+        if self.in_bounds(&start) && passable(&start) {
+            queue.push_back(start);
+        }
+
+        while let Some(coordinate) = queue.pop_front() {
+            if self
+                .limit
+                .is_some_and(|limit| filled.coordinates.len() >= limit)
+            {
+                filled.limit_reached = true;
+                break;
+            }
+
+            for neighbor in self.connectivity.neighbors(&coordinate) {
+                if seen.insert(neighbor) && self.in_bounds(&neighbor) && passable(&neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+            filled.coordinates.push(coordinate);
+        }
+        filled
+    }
+
+    fn in_bounds(&self, coordinate: &Coordinate) -> bool {
+        self.bounds
+            .as_ref()
+            .is_none_or(|bounds| bounds.contains(coordinate))
+    }
+}
+
+/// Connected components within a `Region`, as labelled by [`connected_components`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct Components {
+    region: Region,
+    labels: Vec<Option<usize>>,
+    count: usize,
+}
+
+impl Components {
+    /// Returns the labelled `Region`.
+    pub fn region(&self) -> &Region {
+        &self.region
+    }
+
+    /// Returns the amount of components.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the label of the component containing `coordinate`.
+    ///
+    /// Labels range from `0` to `count() - 1`. Returns `None` if `coordinate` isn't part of any component.
+    pub fn label(&self, coordinate: &Coordinate) -> Option<usize> {
+        self.labels[self.region.index_of(coordinate)?]
+    }
+
+    /// Iterates over all `Coordinate`s of the component with the given `label`, in the `Region`'s iteration order.
+    pub fn component(&self, label: usize) -> impl Iterator<Item = Coordinate> + '_ {
+        self.labels
+            .iter()
+            .enumerate()
+            .filter(move |(_, &other)| other == Some(label))
+            .filter_map(|(index, _)| self.region.coordinate_at(index))
+    }
+}
+
+/// Labels every `Coordinate` within `region` for which `member` returns `true` by its connected component.
+///
+/// Components are numbered in the order in which their first `Coordinate` appears in the `Region`.
+///
+/// # Panics
+///
+/// Panics if the volume of `region` doesn't fit into a `usize`.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// #     test().unwrap();
+/// # }
+/// # fn test() -> Option<()> {
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::flood_fill::connected_components;
+///
+/// let region = Region::new(Coordinate::splat(0), NonZero::new(3)?);
+/// let components = connected_components(&region, Connectivity::Six, |pos| pos.x != 1);
+///
+/// assert_eq!(components.count(), 2);
+/// assert_eq!(components.label(&Coordinate::new(0, 2, 2)), Some(0));
+/// assert_eq!(components.label(&Coordinate::new(1, 0, 0)), None);
+/// assert_eq!(components.label(&Coordinate::new(2, 0, 0)), Some(1));
+/// # Some(())
+/// # }
+/// ```
+pub fn connected_components<F>(
+    region: &Region,
+    connectivity: Connectivity,
+    mut member: F,
+) -> Components
+where
+    F: FnMut(&Coordinate) -> bool,
+{
+    let volume = region
+        .volume_usize()
+        .expect("the region is too large to be labelled");
+    let members: Vec<bool> = (0..volume)
+        .map(|index| {
+            region
+                .coordinate_at(index)
+                .is_some_and(|coordinate| member(&coordinate))
+        })
+        .collect();
+    let mut labels = vec![None; members.len()];
+    let mut count = 0;
+    let mut queue = VecDeque::new();
+
+    for start in 0..members.len() {
+        if !members[start] || labels[start].is_some() {
+            continue;
+        }
+
+        labels[start] = Some(count);
+        queue.push_back(start);
+        while let Some(index) = queue.pop_front() {
+            let Some(coordinate) = region.coordinate_at(index) else {
+                continue;
+            };
+            for neighbor in connectivity.neighbors(&coordinate) {
+                if let Some(neighbor) = region.index_of(&neighbor) {
+                    if members[neighbor] && labels[neighbor].is_none() {
+                        labels[neighbor] = Some(count);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        count += 1;
+    }
+
+    Components {
+        region: region.to_owned(),
+        labels,
+        count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::tests::region;
+
+    #[test]
+    fn fill_respects_connectivity() {
+        let diagonal = |pos: &Coordinate| pos.x == pos.y && pos.z == 0;
+        let bounds = region(Coordinate::splat(0), 4);
+
+        let six = FloodFill::new(Connectivity::Six).bounds(bounds.clone());
+        assert_eq!(
+            six.fill(Coordinate::splat(0), diagonal).coordinates.len(),
+            1
+        );
+
+        let eighteen = FloodFill::new(Connectivity::Eighteen).bounds(bounds);
+        assert_eq!(
+            eighteen
+                .fill(Coordinate::splat(0), diagonal)
+                .coordinates
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn fill_impassable_start() {
+        let filled = FloodFill::new(Connectivity::Six).fill(Coordinate::splat(0), |_| false);
+        assert!(filled.coordinates.is_empty());
+        assert!(!filled.limit_reached);
+    }
+
+    #[test]
+    fn fill_limit() {
+        let filled = FloodFill::new(Connectivity::TwentySix)
+            .limit(100)
+            .fill(Coordinate::splat(0), |_| true);
+        assert_eq!(filled.coordinates.len(), 100);
+        assert!(filled.limit_reached);
+    }
+
+    #[test]
+    fn fill_at_coordinate_limits() {
+        let filled = FloodFill::new(Connectivity::TwentySix).fill(Coordinate::MAX, |pos| {
+            pos.x > CoordinateType::MAX - 2
+                && pos.y > CoordinateType::MAX - 2
+                && pos.z > CoordinateType::MAX - 2
+        });
+        assert_eq!(filled.coordinates.len(), 8);
+    }
+
+    #[test]
+    fn components() {
+        let region = region(Coordinate::splat(0), 4);
+        let components = connected_components(&region, Connectivity::Six, |pos| {
+            (pos.x + pos.y + pos.z) % 2 == 0
+        });
+        assert_eq!(components.count(), 32);
+
+        let components = connected_components(&region, Connectivity::Eighteen, |pos| {
+            (pos.x + pos.y + pos.z) % 2 == 0
+        });
+        assert_eq!(components.count(), 1);
+        assert_eq!(components.component(0).count(), 32);
+    }
+
+    #[test]
+    fn mask_components() {
+        let region = region(Coordinate::splat(0), 3);
+        let mask = RegionMask::from_coordinates(
+            region,
+            [
+                Coordinate::splat(0),
+                Coordinate::splat(2),
+                Coordinate::new(2, 2, 1),
+            ],
+        )
+        .unwrap();
+        let components = mask.connected_components(Connectivity::Six);
+        assert_eq!(components.count(), 2);
+        assert_eq!(components.label(&Coordinate::new(2, 2, 1)), Some(1));
+    }
+}
@@ -0,0 +1,83 @@
+//! Conversions from and to the vector types of other math libraries.
+//!
+//! Each library is behind a feature of the same name. Integer vectors convert losslessly, float
+//! vectors are rounded down towards negative infinity and fail if any axis is out of range.
+
+use thiserror::Error;
+
+use crate::prelude::*;
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[derive(Debug, Clone, Copy, PartialEq, Error)]
+#[error("{value} on the {axis} axis can't be represented by the coordinate's field type.")]
+pub struct FloatConversionError {
+    pub axis: Axis,
+    pub value: f64,
+}
+
+/// Rounds `value` down and converts it to `T`.
+pub(crate) fn floor<T: TryFrom<i64>>(axis: Axis, value: f64) -> Result<T, FloatConversionError> {
+    let error = FloatConversionError { axis, value };
+    // Also rejects NaN.
+    if !(value >= i64::MIN as f64 && value < i64::MAX as f64) {
+        return Err(error);
+    }
+    let truncated = value as i64;
+    let floored = if truncated as f64 > value {
+        truncated - 1
+    } else {
+        truncated
+    };
+    T::try_from(floored).map_err(|_| error)
+}
+
+/// Rounds `[x, y, z]` down and converts them to a `T` coordinate.
+pub(crate) fn floor_all<T, C>([x, y, z]: [f64; 3]) -> Result<C, FloatConversionError>
+where
+    T: TryFrom<i64>,
+    C: From<[T; 3]>,
+{
+    Ok(C::from([
+        floor(Axis::X, x)?,
+        floor(Axis::Y, y)?,
+        floor(Axis::Z, z)?,
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floors_towards_negative_infinity() {
+        assert_eq!(floor::<i32>(Axis::X, 1.9), Ok(1));
+        assert_eq!(floor::<i32>(Axis::X, -0.5), Ok(-1));
+        assert_eq!(floor::<i32>(Axis::X, -2.0), Ok(-2));
+        assert_eq!(floor::<u32>(Axis::X, 0.99), Ok(0));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let error = FloatConversionError {
+            axis: Axis::Y,
+            value: -0.5,
+        };
+        assert_eq!(floor::<u32>(Axis::Y, -0.5), Err(error));
+        assert!(floor::<i32>(Axis::Z, f64::NAN).is_err());
+        assert!(floor::<i32>(Axis::Z, f64::INFINITY).is_err());
+        assert!(floor::<i32>(Axis::Z, 2_147_483_648.0).is_err());
+        assert_eq!(
+            floor::<i32>(Axis::Z, -2_147_483_648.5),
+            Err(FloatConversionError {
+                axis: Axis::Z,
+                value: -2_147_483_648.5,
+            })
+        );
+    }
+}
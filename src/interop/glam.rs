@@ -0,0 +1,98 @@
+use glam::{DVec3, IVec3, UVec3, Vec3};
+
+use super::{floor_all, FloatConversionError};
+use crate::prelude::*;
+
+impl From<IVec3> for SignedCoordinate<i32> {
+    fn from(vector: IVec3) -> Self {
+        Self::from(vector.to_array())
+    }
+}
+
+impl From<SignedCoordinate<i32>> for IVec3 {
+    fn from(coordinate: SignedCoordinate<i32>) -> Self {
+        Self::from_array(coordinate.into())
+    }
+}
+
+impl From<UVec3> for UnsignedCoordinate<u32> {
+    fn from(vector: UVec3) -> Self {
+        Self::from(vector.to_array())
+    }
+}
+
+impl From<UnsignedCoordinate<u32>> for UVec3 {
+    fn from(coordinate: UnsignedCoordinate<u32>) -> Self {
+        Self::from_array(coordinate.into())
+    }
+}
+
+impl From<SignedCoordinate<i32>> for DVec3 {
+    fn from(coordinate: SignedCoordinate<i32>) -> Self {
+        IVec3::from(coordinate).as_dvec3()
+    }
+}
+
+impl From<UnsignedCoordinate<u32>> for DVec3 {
+    fn from(coordinate: UnsignedCoordinate<u32>) -> Self {
+        UVec3::from(coordinate).as_dvec3()
+    }
+}
+
+impl TryFrom<Vec3> for SignedCoordinate<i32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vec3) -> Result<Self, Self::Error> {
+        floor_all(vector.as_dvec3().to_array())
+    }
+}
+
+impl TryFrom<DVec3> for SignedCoordinate<i32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: DVec3) -> Result<Self, Self::Error> {
+        floor_all(vector.to_array())
+    }
+}
+
+impl TryFrom<Vec3> for UnsignedCoordinate<u32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vec3) -> Result<Self, Self::Error> {
+        floor_all(vector.as_dvec3().to_array())
+    }
+}
+
+impl TryFrom<DVec3> for UnsignedCoordinate<u32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: DVec3) -> Result<Self, Self::Error> {
+        floor_all(vector.to_array())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_round_trip() {
+        let coordinate = Coordinate::new(i32::MIN, 0, i32::MAX);
+        assert_eq!(Coordinate::from(IVec3::from(coordinate)), coordinate);
+
+        let coordinate = UnsignedCoordinate::new(0, 1, u32::MAX);
+        assert_eq!(
+            UnsignedCoordinate::from(UVec3::from(coordinate)),
+            coordinate
+        );
+    }
+
+    #[test]
+    fn floats() {
+        let coordinate = Coordinate::try_from(Vec3::new(0.5, -0.5, -3.0));
+        assert_eq!(coordinate, Ok(Coordinate::new(0, -1, -3)));
+
+        let error = UnsignedCoordinate::<u32>::try_from(DVec3::new(1.0, 2.0, -1.0));
+        assert_eq!(error.map_err(|error| error.axis), Err(Axis::Z));
+    }
+}
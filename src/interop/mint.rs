@@ -0,0 +1,84 @@
+use mint::Vector3;
+
+use super::{floor_all, FloatConversionError};
+use crate::prelude::*;
+
+impl From<Vector3<i32>> for SignedCoordinate<i32> {
+    fn from(vector: Vector3<i32>) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<SignedCoordinate<i32>> for Vector3<i32> {
+    fn from(coordinate: SignedCoordinate<i32>) -> Self {
+        Self::from(<[i32; 3]>::from(coordinate))
+    }
+}
+
+impl From<Vector3<u32>> for UnsignedCoordinate<u32> {
+    fn from(vector: Vector3<u32>) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<UnsignedCoordinate<u32>> for Vector3<u32> {
+    fn from(coordinate: UnsignedCoordinate<u32>) -> Self {
+        Self::from(<[u32; 3]>::from(coordinate))
+    }
+}
+
+impl TryFrom<Vector3<f32>> for SignedCoordinate<i32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vector3<f32>) -> Result<Self, Self::Error> {
+        floor_all([vector.x.into(), vector.y.into(), vector.z.into()])
+    }
+}
+
+impl TryFrom<Vector3<f64>> for SignedCoordinate<i32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vector3<f64>) -> Result<Self, Self::Error> {
+        floor_all(vector.into())
+    }
+}
+
+impl TryFrom<Vector3<f32>> for UnsignedCoordinate<u32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vector3<f32>) -> Result<Self, Self::Error> {
+        floor_all([vector.x.into(), vector.y.into(), vector.z.into()])
+    }
+}
+
+impl TryFrom<Vector3<f64>> for UnsignedCoordinate<u32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vector3<f64>) -> Result<Self, Self::Error> {
+        floor_all(vector.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_round_trip() {
+        let coordinate = Coordinate::new(-1, 2, -3);
+        assert_eq!(Coordinate::from(Vector3::from(coordinate)), coordinate);
+    }
+
+    #[test]
+    fn floats() {
+        let vector = Vector3::from([-0.1f32, 7.9, 1e10]);
+        let error = Coordinate::try_from(vector).unwrap_err();
+        assert_eq!(error.axis, Axis::Z);
+
+        let vector = Vector3::from([2.5f64, 0.0, 3.0]);
+        assert_eq!(
+            UnsignedCoordinate::try_from(vector),
+            Ok(UnsignedCoordinate::new(2, 0, 3))
+        );
+    }
+}
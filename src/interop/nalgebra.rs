@@ -0,0 +1,83 @@
+use nalgebra::Vector3;
+
+use super::{floor_all, FloatConversionError};
+use crate::prelude::*;
+
+impl From<Vector3<i32>> for SignedCoordinate<i32> {
+    fn from(vector: Vector3<i32>) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<SignedCoordinate<i32>> for Vector3<i32> {
+    fn from(coordinate: SignedCoordinate<i32>) -> Self {
+        Self::new(coordinate.x, coordinate.y, coordinate.z)
+    }
+}
+
+impl From<Vector3<u32>> for UnsignedCoordinate<u32> {
+    fn from(vector: Vector3<u32>) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<UnsignedCoordinate<u32>> for Vector3<u32> {
+    fn from(coordinate: UnsignedCoordinate<u32>) -> Self {
+        Self::new(coordinate.x, coordinate.y, coordinate.z)
+    }
+}
+
+impl TryFrom<Vector3<f32>> for SignedCoordinate<i32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vector3<f32>) -> Result<Self, Self::Error> {
+        floor_all([vector.x.into(), vector.y.into(), vector.z.into()])
+    }
+}
+
+impl TryFrom<Vector3<f64>> for SignedCoordinate<i32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vector3<f64>) -> Result<Self, Self::Error> {
+        floor_all(vector.into())
+    }
+}
+
+impl TryFrom<Vector3<f32>> for UnsignedCoordinate<u32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vector3<f32>) -> Result<Self, Self::Error> {
+        floor_all([vector.x.into(), vector.y.into(), vector.z.into()])
+    }
+}
+
+impl TryFrom<Vector3<f64>> for UnsignedCoordinate<u32> {
+    type Error = FloatConversionError;
+
+    fn try_from(vector: Vector3<f64>) -> Result<Self, Self::Error> {
+        floor_all(vector.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_round_trip() {
+        let coordinate = UnsignedCoordinate::new(1, 2, u32::MAX);
+        assert_eq!(
+            UnsignedCoordinate::from(Vector3::from(coordinate)),
+            coordinate
+        );
+    }
+
+    #[test]
+    fn floats() {
+        let coordinate = Coordinate::try_from(Vector3::new(-1.5f64, 0.25, 9.0));
+        assert_eq!(coordinate, Ok(Coordinate::new(-2, 0, 9)));
+
+        let error = Coordinate::try_from(Vector3::new(f32::NAN, 0.0, 0.0)).unwrap_err();
+        assert_eq!(error.axis, Axis::X);
+    }
+}
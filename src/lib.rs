@@ -4,9 +4,41 @@
 //! Argentum and some utilities related to it.
 //!
 //! For more information about Argentum, see the `argentum_game` crate.
+//!
+//! # Features
+//!
+//! - `std` (default): Without it, the crate is `#![no_std]` and only depends on `alloc`.
+//! - `quickcheck`: Implements `quickcheck::Arbitrary` for the coordinate types whose field type
+//!   implements it.
+//! - `proptest`: Provides `proptest` strategies in [`strategies`].
+//! - `bytemuck`: Implements `bytemuck::Pod` and `bytemuck::Zeroable` for the coordinate types.
+//! - `glam`, `mint`, `nalgebra`: Conversions from and to their vector types, see [`interop`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod affine;
+pub mod command;
+pub mod connectivity;
 mod coordinate;
+pub mod distance;
+pub mod flood_fill;
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra"))]
+pub mod interop;
 mod macros;
+pub mod ordering;
+pub mod pathfinding;
 pub mod region;
+pub mod space;
+#[cfg(feature = "proptest")]
+pub mod strategies;
 
 pub mod prelude;
+
+/// Dependencies used by the exported macros, so crates calling them don't need to depend on them.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "quickcheck")]
+    pub use quickcheck;
+}
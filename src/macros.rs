@@ -1,5 +1,8 @@
 /// Allows you to generate a new type similar to `Coordinate`.
 ///
+/// `quickcheck::Arbitrary` is only implemented if the `quickcheck` feature of this crate is enabled.
+/// The calling crate doesn't need to depend on `quickcheck` or `thiserror` either way.
+///
 /// # Inputs
 ///
 /// - `$name` = The name of the new type.
@@ -110,23 +113,20 @@ macro_rules! generate_coordinate_type {
             }
         }
 
-        use quickcheck::{Arbitrary, Gen};
-        impl Arbitrary for $name {
-            fn arbitrary(g: &mut Gen) -> Self {
-                Self::new(
-                    $coord_type::arbitrary(g),
-                    $coord_type::arbitrary(g),
-                    $coord_type::arbitrary(g),
-                )
+        $crate::__generate_arbitrary!($name, $coord_type);
+
+        #[derive(Debug, Clone)]
+        pub struct CoordinateOverflowError(pub $name);
+
+        impl Display for CoordinateOverflowError {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                write!(f, "{} is experiencing integer overflow.", self.0)
             }
         }
 
-        use thiserror::Error;
-        #[derive(Debug, Clone, Error)]
-        #[error("{0} is experiencing integer overflow.")]
-        pub struct CoordinateOverflowError(pub $name);
+        impl ::core::error::Error for CoordinateOverflowError {}
 
-        use std::ops::*;
+        use core::ops::*;
 
         impl Add for $name {
             type Output = Self;
@@ -243,3 +243,29 @@ macro_rules! generate_coordinate_type {
         }
     };
 }
+
+/// Implements `quickcheck::Arbitrary` for a type generated by [`generate_coordinate_type`], if the
+/// `quickcheck` feature of this crate is enabled.
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __generate_arbitrary {
+    ($name:ident, $coord_type:ident) => {
+        impl $crate::__private::quickcheck::Arbitrary for $name {
+            fn arbitrary(g: &mut $crate::__private::quickcheck::Gen) -> Self {
+                let mut arbitrary =
+                    || <$coord_type as $crate::__private::quickcheck::Arbitrary>::arbitrary(g);
+                Self::new(arbitrary(), arbitrary(), arbitrary())
+            }
+        }
+    };
+}
+
+/// Implements `quickcheck::Arbitrary` for a type generated by [`generate_coordinate_type`], if the
+/// `quickcheck` feature of this crate is enabled.
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __generate_arbitrary {
+    ($name:ident, $coord_type:ident) => {};
+}
@@ -0,0 +1,254 @@
+//! Alternative orderings of `Coordinate`s for sorted collections.
+//!
+//! `Coordinate`s themselves are ordered z-major, matching the iteration order of `Region`.
+
+use core::cmp::Ordering;
+
+use crate::{
+    distance::{euclidean_squared, Metric},
+    prelude::*,
+};
+
+/// Orders `Coordinate`s along the Z-order (Morton) curve, keeping nearby `Coordinate`s close
+/// together in sorted collections.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::ordering::MortonOrder;
+///
+/// let set: BTreeSet<_> = [
+///     Coordinate::new(2, 0, 0),
+///     Coordinate::new(0, 1, 0),
+///     Coordinate::new(1, 0, 0),
+/// ]
+/// .into_iter()
+/// .map(MortonOrder)
+/// .collect();
+///
+/// let sorted: Vec<_> = set.into_iter().map(|MortonOrder(c)| c).collect();
+/// assert_eq!(sorted, [Coordinate::new(1, 0, 0), Coordinate::new(0, 1, 0), Coordinate::new(2, 0, 0)]);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct MortonOrder<C = Coordinate>(pub C);
+
+impl<C> MortonOrder<C>
+where
+    C: CoordinateTrait + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: CoordinateField,
+{
+    /// Returns the position of the `Coordinate` along the Z-order curve.
+    ///
+    /// The bits of all axes are interleaved, with `x` in the lowest bit of each triple.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field type has more than 42 bits, as the code of all three axes wouldn't fit
+    /// into a `u128`. The ordering itself supports every field type.
+    pub fn code(&self) -> u128 {
+        let bits = field_bits::<C::FieldType>();
+        assert!(
+            bits * 3 <= u128::BITS,
+            "Morton codes of {bits}-bit fields don't fit into a u128"
+        );
+        let spread =
+            |n: u128| (0..bits).fold(0u128, |code, bit| code | (((n >> bit) & 1) << (bit * 3)));
+        let [x, y, z] = self.keys();
+        spread(x) | (spread(y) << 1) | (spread(z) << 2)
+    }
+
+    /// Returns the order-preserving keys of all axes.
+    fn keys(&self) -> [u128; 3] {
+        self.0.into().map(field_key)
+    }
+}
+
+/// Maps `n` onto `0..` in order, e.g. `i32::MIN..=i32::MAX` onto `0..=u32::MAX`.
+fn field_key<T: CoordinateField>(n: T) -> u128 {
+    if T::MIN.is_zero() {
+        n.to_u128()
+    } else {
+        // Subtracting the minimum flips the sign bit of the two's complement.
+        n.to_i128()
+            .zip(T::MIN.to_i128())
+            .map(|(n, min)| n.wrapping_sub(min) as u128)
+    }
+    .expect("coordinate fields fit into 128 bits")
+}
+
+/// Returns the number of bits the keys of the field type `T` occupy.
+fn field_bits<T: CoordinateField>() -> u32 {
+    u128::BITS - field_key(T::MAX).leading_zeros()
+}
+
+impl<C> PartialOrd for MortonOrder<C>
+where
+    C: CoordinateTrait + Copy + Eq + Into<[C::FieldType; 3]>,
+    C::FieldType: CoordinateField,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for MortonOrder<C>
+where
+    C: CoordinateTrait + Copy + Eq + Into<[C::FieldType; 3]>,
+    C::FieldType: CoordinateField,
+{
+    /// Compares the codes without computing them, so fields of any width are supported.
+    ///
+    /// The axis whose keys differ in the highest bit decides, with `z` before `y` before `x`
+    /// within the same bit, matching the interleaving of [`MortonOrder::code`].
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.keys(), other.keys());
+        let mut deciding = 2;
+        for axis in [1, 0] {
+            let highest = a[deciding] ^ b[deciding];
+            let difference = a[axis] ^ b[axis];
+            if highest < difference && highest < (highest ^ difference) {
+                deciding = axis;
+            }
+        }
+        a[deciding].cmp(&b[deciding])
+    }
+}
+
+/// Orders `Coordinate`s by their distance from an origin, e.g. for nearest-first processing in a
+/// `BinaryHeap`.
+///
+/// Ties are broken by the ordering of `Coordinate`. Only values created with the same origin and
+/// metric are meaningfully comparable.
+///
+/// # Examples
+///
+/// ```
+/// use std::{cmp::Reverse, collections::BinaryHeap};
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::ordering::DistanceFrom;
+///
+/// let origin = Coordinate::splat(0);
+/// let mut heap = BinaryHeap::new();
+/// heap.push(Reverse(DistanceFrom::new(&origin, Coordinate::new(3, 0, 0))));
+/// heap.push(Reverse(DistanceFrom::new(&origin, Coordinate::new(1, 1, 0))));
+///
+/// let Reverse(nearest) = heap.pop().unwrap();
+/// assert_eq!(nearest.coordinate(), &Coordinate::new(1, 1, 0));
+/// assert_eq!(nearest.distance(), 2);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct DistanceFrom {
+    distance: u128,
+    coordinate: Coordinate,
+}
+
+impl DistanceFrom {
+    /// Wraps `coordinate`, ordering it by its squared Euclidean distance from `origin`.
+    pub fn new(origin: &Coordinate, coordinate: Coordinate) -> Self {
+        Self {
+            distance: euclidean_squared(origin, &coordinate),
+            coordinate,
+        }
+    }
+
+    /// Wraps `coordinate`, ordering it by its distance from `origin` according to `metric`.
+    pub fn with_metric(origin: &Coordinate, coordinate: Coordinate, metric: Metric) -> Self {
+        Self {
+            distance: u128::from(metric.distance(origin, &coordinate)),
+            coordinate,
+        }
+    }
+
+    /// Returns the distance the `Coordinate` is ordered by.
+    pub fn distance(&self) -> u128 {
+        self.distance
+    }
+
+    /// Returns the wrapped `Coordinate`.
+    pub fn coordinate(&self) -> &Coordinate {
+        &self.coordinate
+    }
+
+    /// Returns the wrapped `Coordinate`.
+    pub fn into_inner(self) -> Coordinate {
+        self.coordinate
+    }
+}
+
+impl PartialOrd for DistanceFrom {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DistanceFrom {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.distance, self.coordinate).cmp(&(other.distance, other.coordinate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn morton_code() {
+        let origin = MortonOrder(Coordinate::splat(0)).code();
+        assert_eq!(MortonOrder(Coordinate::new(1, 0, 0)).code() - origin, 0b001);
+        assert_eq!(MortonOrder(Coordinate::new(0, 1, 0)).code() - origin, 0b010);
+        assert_eq!(MortonOrder(Coordinate::new(0, 0, 1)).code() - origin, 0b100);
+        assert_eq!(MortonOrder(Coordinate::MIN).code(), 0);
+        assert_eq!(MortonOrder(Coordinate::MAX).code(), (1 << 96) - 1);
+    }
+
+    #[test]
+    fn morton_sign() {
+        assert!(MortonOrder(Coordinate::splat(-1)) < MortonOrder(Coordinate::splat(0)));
+    }
+
+    #[test]
+    fn morton_field_types() {
+        assert_eq!(MortonOrder(UnsignedCoordinate::new(0u8, 0, 0)).code(), 0);
+        assert_eq!(
+            MortonOrder(UnsignedCoordinate::new(1u8, 1, 0)).code(),
+            0b011
+        );
+        assert_eq!(
+            MortonOrder(UnsignedCoordinate::<u8>::MAX).code(),
+            (1 << 24) - 1
+        );
+        assert_eq!(MortonOrder(SignedCoordinate::<i8>::MIN).code(), 0);
+
+        let a = MortonOrder(SignedCoordinate::new(i64::MIN, 0, 0));
+        let b = MortonOrder(SignedCoordinate::new(0, i64::MIN, 0));
+        assert!(b < a);
+        let x = MortonOrder(UnsignedCoordinate::new(u128::MAX, 0, 0));
+        let y = MortonOrder(UnsignedCoordinate::new(0, u128::MAX, 0));
+        assert!(x < y);
+    }
+
+    #[test]
+    #[should_panic(expected = "64-bit fields")]
+    fn morton_code_too_wide() {
+        MortonOrder(UnsignedCoordinate::<u64>::MIN).code();
+    }
+
+    quickcheck::quickcheck! {
+        fn morton_order_matches_code(a: (i16, i16, i16), b: (i16, i16, i16)) -> bool {
+            let (a, b) = (MortonOrder(SignedCoordinate::from(a)), MortonOrder(SignedCoordinate::from(b)));
+            a.cmp(&b) == a.code().cmp(&b.code())
+        }
+    }
+
+    #[test]
+    fn distance_ties() {
+        let origin = Coordinate::splat(0);
+        let a = DistanceFrom::with_metric(&origin, Coordinate::new(0, 2, 0), Metric::Chebyshev);
+        let b = DistanceFrom::with_metric(&origin, Coordinate::new(2, 2, 0), Metric::Chebyshev);
+        assert_eq!(a.distance(), b.distance());
+        assert!(a < b);
+        assert_ne!(a, b);
+    }
+}
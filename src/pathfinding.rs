@@ -0,0 +1,525 @@
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BinaryHeap},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Reverse;
+
+use crate::{
+    connectivity::checked_offset,
+    distance::{chebyshev, Metric},
+    prelude::*,
+};
+
+pub use hierarchical::HierarchicalPathfinder;
+
+use jump::JumpPoints;
+
+mod hierarchical;
+mod jump;
+
+/// A* pathfinder over the `Coordinate` grid.
+///
+/// The heuristic is derived from a distance [`Metric`], divided by the largest distance any single
+/// move can cover and multiplied by the cheapest possible move cost. This keeps it admissible for
+/// any set of moves, as long as [`AStar::min_cost`] is a lower bound of the costs returned by the
+/// cost function.
+///
+/// Searches over the moves of a [`Connectivity`] can opt into jump point search, see
+/// [`AStar::jump_points`], which skips most of the `Coordinate`s in open areas.
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::pathfinding::PathStatus;
+///
+/// let wall = |pos: &Coordinate| pos.x == 2 && pos.z < 3;
+/// let result = AStar::new(Connectivity::Six)
+///     .max_expanded(10_000)
+///     .find(Coordinate::new(0, 0, 0), Coordinate::new(4, 0, 0), |_, to| (!wall(to)).then_some(1));
+///
+/// assert_eq!(result.status, PathStatus::Found);
+/// assert_eq!(result.cost, 10);
+/// assert_eq!(result.path.first(), Some(&Coordinate::new(0, 0, 0)));
+/// assert_eq!(result.path.last(), Some(&Coordinate::new(4, 0, 0)));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct AStar {
+    moves: Vec<[CoordinateType; 3]>,
+    connectivity: Option<Connectivity>,
+    jump_bounds: Option<Region>,
+    metric: Metric,
+    min_cost: u64,
+    max_expanded: Option<usize>,
+}
+
+/// Outcome of a path search.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PathStatus {
+    /// The goal was reached.
+    Found,
+    /// Every reachable `Coordinate` was explored without finding the goal.
+    Unreachable,
+    /// The search gave up after expanding the maximum amount of nodes.
+    BudgetExhausted,
+}
+
+/// Result of a path search.
+///
+/// If the goal wasn't reached, `path` leads to the explored `Coordinate` that is closest to the goal.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PathResult {
+    /// All `Coordinate`s along the path, including the start and the last reached `Coordinate`.
+    pub path: Vec<Coordinate>,
+    /// Total cost of `path`.
+    pub cost: u64,
+    /// Whether the goal was reached.
+    pub status: PathStatus,
+    /// Amount of nodes that were expanded during the search.
+    pub expanded: usize,
+}
+
+impl PathResult {
+    /// Returns `true` if `path` ends at the goal.
+    pub fn is_complete(&self) -> bool {
+        self.status == PathStatus::Found
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    coordinate: Coordinate,
+    parent: Option<usize>,
+    cost: u64,
+    heuristic: u64,
+    closed: bool,
+}
+
+impl AStar {
+    /// Creates a new `AStar` moving to the neighbours given by `connectivity`.
+    pub fn new(connectivity: Connectivity) -> Self {
+        Self {
+            connectivity: Some(connectivity),
+            ..Self::with_moves(connectivity.offsets().iter().copied())
+        }
+    }
+
+    /// Creates a new `AStar` using custom move offsets, e.g. for climbing or falling.
+    pub fn with_moves<I>(moves: I) -> Self
+    where
+        I: IntoIterator<Item = [CoordinateType; 3]>,
+    {
+        Self {
+            moves: moves.into_iter().collect(),
+            connectivity: None,
+            jump_bounds: None,
+            metric: Metric::default(),
+            min_cost: 1,
+            max_expanded: None,
+        }
+    }
+
+    /// Adds additional move offsets.
+    ///
+    /// The moves no longer are those of a `Connectivity`, so this turns off
+    /// [jump point search](AStar::jump_points).
+    pub fn add_moves<I>(mut self, moves: I) -> Self
+    where
+        I: IntoIterator<Item = [CoordinateType; 3]>,
+    {
+        self.moves.extend(moves);
+        self.connectivity = None;
+        self
+    }
+
+    /// Enables jump point search, which only expands the `Coordinate`s where the cheapest path may
+    /// have to turn and skips over the rest.
+    ///
+    /// The search is limited to `bounds`, so jumps across open areas come to an end. Paths are
+    /// still optimal, given that every move is possible if and only if its target is passable and
+    /// that every move costs the same, regardless of its offset. The cost function is called at
+    /// most once per target `Coordinate` and its result reused for every move into it.
+    ///
+    /// Far fewer nodes are expanded, which stretches [`AStar::max_expanded`] budgets, but every
+    /// expansion scans ahead until the next jump point and may call `cost` for many `Coordinate`s.
+    ///
+    /// Only applies to the moves of a `Connectivity`, as given to [`AStar::new`]. Custom moves,
+    /// e.g. from [`AStar::add_moves`], are searched without pruning, as their shortest paths
+    /// can't be put into a canonical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::{distance::Metric, prelude::*};
+    ///
+    /// let bounds = Region::new(Coordinate::splat(0), NonZero::new(32).unwrap());
+    /// let wall = |pos: &Coordinate| pos.x == 16 && pos.y < 24;
+    /// let result = AStar::new(Connectivity::TwentySix)
+    ///     .metric(Metric::Chebyshev)
+    ///     .jump_points(bounds)
+    ///     .find(Coordinate::splat(0), Coordinate::new(31, 0, 0), |_, to| (!wall(to)).then_some(1));
+    ///
+    /// assert!(result.is_complete());
+    /// assert_eq!(result.cost, 48);
+    /// assert_eq!(result.path.len(), 49);
+    /// assert!(result.expanded < 100);
+    /// ```
+    pub fn jump_points(mut self, bounds: Region) -> Self {
+        self.jump_bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the distance metric the heuristic is based on.
+    pub fn metric(mut self, metric: Metric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Sets the lowest cost a single move can have. Defaults to `1`.
+    ///
+    /// Setting this to `0` turns the search into Dijkstra's algorithm.
+    pub fn min_cost(mut self, min_cost: u64) -> Self {
+        self.min_cost = min_cost;
+        self
+    }
+
+    /// Limits the amount of nodes the search may expand.
+    pub fn max_expanded(mut self, max_expanded: usize) -> Self {
+        self.max_expanded = Some(max_expanded);
+        self
+    }
+
+    /// Searches for the cheapest path from `start` to `goal`.
+    ///
+    /// `cost` is called with the current and the next `Coordinate` of a move and returns the cost
+    /// of that move, or `None` if the move is impossible.
+    pub fn find<F>(&self, start: Coordinate, goal: Coordinate, mut cost: F) -> PathResult
+    where
+        F: FnMut(&Coordinate, &Coordinate) -> Option<u64>,
+    {
+        if let (Some(connectivity), Some(bounds)) = (self.connectivity, &self.jump_bounds) {
+            let mut jump_points = JumpPoints::new(connectivity, bounds, goal, cost);
+            return self.search(start, goal, true, |current, parent, successors| {
+                jump_points.successors(current, parent, successors)
+            });
+        }
+
+        self.search(start, goal, false, |current, _, successors| {
+            for &offset in &self.moves {
+                let Some(next) = checked_offset(current, offset) else {
+                    continue;
+                };
+                if let Some(move_cost) = cost(current, &next) {
+                    successors.push((next, move_cost));
+                }
+            }
+        })
+    }
+
+    /// Runs A* with the given successor function, which pushes every `Coordinate` reachable from
+    /// the current one, given the one it was reached from, along with the cost of getting there.
+    ///
+    /// With `jumps` set, successors may lie several moves away in a straight line, which are filled
+    /// into the path.
+    fn search<S>(
+        &self,
+        start: Coordinate,
+        goal: Coordinate,
+        jumps: bool,
+        mut successors: S,
+    ) -> PathResult
+    where
+        S: FnMut(&Coordinate, Option<&Coordinate>, &mut Vec<(Coordinate, u64)>),
+    {
+        let span = self.span();
+        let mut nodes = vec![Node {
+            heuristic: self.heuristic(span, &start, &goal),
+            coordinate: start,
+            parent: None,
+            cost: 0,
+            closed: false,
+        }];
+        let mut indices = BTreeMap::from([(nodes[0].coordinate, 0)]);
+        let mut open = BinaryHeap::from([Reverse((nodes[0].heuristic, nodes[0].heuristic, 0, 0))]);
+        let mut closest = 0;
+        let mut expanded = 0;
+        let mut next_nodes = Vec::new();
+
+        let status = loop {
+            let Some(Reverse((_, _, node_cost, index))) = open.pop() else {
+                break PathStatus::Unreachable;
+            };
+            if nodes[index].closed || node_cost > nodes[index].cost {
+                continue;
+            }
+            if (nodes[index].heuristic, nodes[index].cost)
+                < (nodes[closest].heuristic, nodes[closest].cost)
+            {
+                closest = index;
+            }
+            if nodes[index].coordinate == goal {
+                closest = index;
+                break PathStatus::Found;
+            }
+            if self.max_expanded.is_some_and(|max| expanded >= max) {
+                break PathStatus::BudgetExhausted;
+            }
+
+            nodes[index].closed = true;
+            expanded += 1;
+
+            let current = nodes[index].coordinate.to_owned();
+            let parent = nodes[index].parent.map(|parent| nodes[parent].coordinate);
+            successors(&current, parent.as_ref(), &mut next_nodes);
+            for (next, move_cost) in next_nodes.drain(..) {
+                let next_cost = nodes[index].cost.saturating_add(move_cost);
+
+                let next_index = *indices.entry(next).or_insert_with(|| {
+                    nodes.push(Node {
+                        heuristic: self.heuristic(span, &next, &goal),
+                        coordinate: next,
+                        parent: None,
+                        cost: u64::MAX,
+                        closed: false,
+                    });
+                    nodes.len() - 1
+                });
+                let node = &mut nodes[next_index];
+                if node.closed || next_cost >= node.cost {
+                    continue;
+                }
+                node.parent = Some(index);
+                node.cost = next_cost;
+                open.push(Reverse((
+                    next_cost.saturating_add(node.heuristic),
+                    node.heuristic,
+                    next_cost,
+                    next_index,
+                )));
+            }
+        };
+
+        let mut path = Vec::new();
+        let mut current = Some(closest);
+        while let Some(index) = current {
+            let coordinate = nodes[index].coordinate.to_owned();
+            current = nodes[index].parent;
+            match current {
+                Some(parent) if jumps => {
+                    path.extend(jump_path(&nodes[parent].coordinate, &coordinate))
+                }
+                _ => path.push(coordinate),
+            }
+        }
+        path.reverse();
+
+        PathResult {
+            path,
+            cost: nodes[closest].cost,
+            status,
+            expanded,
+        }
+    }
+
+    /// Largest distance a single move can cover.
+    fn span(&self) -> u64 {
+        let origin = Coordinate::splat(0);
+        self.moves
+            .iter()
+            .map(|&[x, y, z]| self.metric.distance(&origin, &Coordinate::new(x, y, z)))
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn heuristic(&self, span: u64, from: &Coordinate, goal: &Coordinate) -> u64 {
+        match span {
+            0 => 0,
+            span => self
+                .metric
+                .distance(from, goal)
+                .div_ceil(span)
+                .saturating_mul(self.min_cost),
+        }
+    }
+}
+
+/// Returns the `Coordinate`s of a jump from `from` to `to` in a straight line, from `to` backwards
+/// and excluding `from`.
+fn jump_path(from: &Coordinate, to: &Coordinate) -> impl Iterator<Item = Coordinate> {
+    let backwards = [
+        from.x.cmp(&to.x) as CoordinateType,
+        from.y.cmp(&to.y) as CoordinateType,
+        from.z.cmp(&to.z) as CoordinateType,
+    ];
+    let steps = chebyshev(from, to);
+    let mut current = Some(*to);
+    (0..steps).map_while(move |_| {
+        let coordinate = current?;
+        current = checked_offset(&coordinate, backwards);
+        Some(coordinate)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZero;
+
+    use super::*;
+
+    #[test]
+    fn straight_line() {
+        let result = AStar::new(Connectivity::Six).find(
+            Coordinate::splat(0),
+            Coordinate::new(0, 0, 5),
+            |_, _| Some(1),
+        );
+        assert_eq!(result.status, PathStatus::Found);
+        assert_eq!(result.path.len(), 6);
+        assert_eq!(result.cost, 5);
+        assert_eq!(result.expanded, 5);
+    }
+
+    #[test]
+    fn diagonal_moves() {
+        let result = AStar::new(Connectivity::TwentySix)
+            .metric(Metric::Chebyshev)
+            .find(Coordinate::splat(0), Coordinate::splat(4), |_, _| Some(1));
+        assert!(result.is_complete());
+        assert_eq!(result.cost, 4);
+    }
+
+    #[test]
+    fn unreachable_returns_partial_path() {
+        let goal = Coordinate::new(5, 0, 0);
+        let result = AStar::new(Connectivity::Six).find(Coordinate::splat(0), goal, |_, to| {
+            (to.x.abs() <= 3 && to.y.abs() <= 3 && to.z.abs() <= 3).then_some(1)
+        });
+        assert_eq!(result.status, PathStatus::Unreachable);
+        assert_eq!(result.path.last(), Some(&Coordinate::new(3, 0, 0)));
+        assert_eq!(result.cost, 3);
+    }
+
+    #[test]
+    fn budget_exhausted() {
+        let result = AStar::new(Connectivity::Six).max_expanded(10).find(
+            Coordinate::splat(0),
+            Coordinate::new(100, 0, 0),
+            |_, to| (to.y == 0 && to.x != 50).then_some(1),
+        );
+        assert_eq!(result.status, PathStatus::BudgetExhausted);
+        assert_eq!(result.expanded, 10);
+        assert_eq!(result.path.first(), Some(&Coordinate::splat(0)));
+    }
+
+    #[test]
+    fn climbing_moves() {
+        let floor = |pos: &Coordinate| pos.y == pos.x.div_euclid(2);
+        let result = AStar::with_moves([[1, 0, 0], [-1, 0, 0], [1, 1, 0], [-1, -1, 0]]).find(
+            Coordinate::splat(0),
+            Coordinate::new(6, 3, 0),
+            |_, to| floor(to).then_some(1),
+        );
+        assert!(result.is_complete());
+        assert_eq!(result.cost, 6);
+    }
+
+    #[test]
+    fn coordinate_limits() {
+        let goal = Coordinate::new(
+            CoordinateType::MAX,
+            CoordinateType::MAX,
+            CoordinateType::MAX - 3,
+        );
+        let result =
+            AStar::new(Connectivity::TwentySix).find(Coordinate::MAX, goal, |_, _| Some(1));
+        assert!(result.is_complete());
+        assert_eq!(result.cost, 3);
+    }
+
+    #[test]
+    fn jump_points_around_wall() {
+        let bounds = Region::new(Coordinate::splat(0), NonZero::new(16).unwrap());
+        let wall = |pos: &Coordinate| pos.x == 8 && (pos.y < 12 || pos.z < 12);
+        let cost = |_: &Coordinate, to: &Coordinate| (!wall(to)).then_some(1);
+        for connectivity in [
+            Connectivity::Six,
+            Connectivity::Eighteen,
+            Connectivity::TwentySix,
+        ] {
+            let astar = AStar::new(connectivity).metric(Metric::Chebyshev);
+            let start = Coordinate::splat(0);
+            let goal = Coordinate::new(15, 0, 0);
+            let plain = astar.find(start, goal, cost);
+            let jumping = astar.jump_points(bounds.clone()).find(start, goal, cost);
+
+            assert!(jumping.is_complete());
+            assert_eq!(jumping.cost, plain.cost);
+            assert_eq!(jumping.path.len() as u64, jumping.cost + 1);
+            assert!(jumping.expanded * 10 < plain.expanded);
+            for pair in jumping.path.windows(2) {
+                let offset: [CoordinateType; 3] = (pair[1] - pair[0]).into();
+                assert!(connectivity.offsets().contains(&offset));
+                assert!(!wall(&pair[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn custom_moves_disable_jump_points() {
+        let bounds = Region::new(Coordinate::splat(-8), NonZero::new(16).unwrap());
+        let astar = AStar::new(Connectivity::Six).jump_points(bounds);
+        let goal = Coordinate::new(6, 3, 0);
+        let floor = |_: &Coordinate, to: &Coordinate| (to.y == to.x.div_euclid(2)).then_some(1);
+        assert!(!astar.find(Coordinate::splat(0), goal, floor).is_complete());
+
+        let result =
+            astar
+                .add_moves([[1, 1, 0], [-1, -1, 0]])
+                .find(Coordinate::splat(0), goal, floor);
+        assert!(result.is_complete());
+        assert_eq!(result.cost, 6);
+    }
+
+    quickcheck::quickcheck! {
+        fn jump_points_find_cheapest_path(
+            seed: u64,
+            size: u8,
+            density: u8,
+            start: (u8, u8, u8),
+            goal: (u8, u8, u8)
+        ) -> bool {
+            let size = size % 6 + 2;
+            let bounds = Region::new(Coordinate::splat(0), NonZero::new(u32::from(size)).unwrap());
+            let position = |(x, y, z): (u8, u8, u8)| {
+                Coordinate::new(
+                    CoordinateType::from(x % size),
+                    CoordinateType::from(y % size),
+                    CoordinateType::from(z % size),
+                )
+            };
+            let (start, goal) = (position(start), position(goal));
+            let blocked = |pos: &Coordinate| {
+                let hash = (seed ^ (pos.x as u64) << 16 ^ (pos.y as u64) << 8 ^ pos.z as u64)
+                    .wrapping_mul(0x9e37_79b9_7f4a_7c15);
+                *pos != goal && (hash >> 56) % 100 < u64::from(density % 60)
+            };
+            let cost = |_: &Coordinate, to: &Coordinate| {
+                (bounds.contains(to) && !blocked(to)).then_some(2)
+            };
+
+            [Connectivity::Six, Connectivity::Eighteen, Connectivity::TwentySix]
+                .into_iter()
+                .all(|connectivity| {
+                    let astar = AStar::new(connectivity).min_cost(2);
+                    let plain = astar.find(start, goal, cost);
+                    let jumping = astar.jump_points(bounds.clone()).find(start, goal, cost);
+                    jumping.status == plain.status
+                        && (!plain.is_complete() || jumping.cost == plain.cost)
+                        && jumping.path.first() == Some(&start)
+                })
+        }
+    }
+}
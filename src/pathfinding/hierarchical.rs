@@ -0,0 +1,576 @@
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+use core::{cmp::Reverse, num::NonZero};
+
+use super::{AStar, PathResult, PathStatus};
+use crate::{connectivity::checked_offset, distance::manhattan, prelude::*, space};
+
+/// Hierarchical pathfinder that plans across chunks first and refines within them afterwards.
+///
+/// The world is divided into cube-shaped chunk `Region`s, aligned to the origin the same way as
+/// [`WorldCoordinate::to_chunk`]. Wherever a run of cells can cross the face shared by two
+/// chunks, a single entrance is placed on either side. Paths between the entrances of a chunk
+/// are computed with [`AStar`] restricted to that chunk, and cached until the chunk is
+/// [invalidated](HierarchicalPathfinder::invalidate).
+///
+/// Moves are limited to the six face neighbours. The resulting paths are not guaranteed to be
+/// optimal, as every crossing between two chunks goes through one of the entrances.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// #     test().unwrap();
+/// # }
+/// # fn test() -> Option<()> {
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let mut pathfinder = HierarchicalPathfinder::new(NonZero::new(8)?);
+/// let floor = |_: &Coordinate, to: &Coordinate| (to.y == 0).then_some(1);
+/// let result = pathfinder.find(Coordinate::splat(0), Coordinate::new(40, 0, -20), floor);
+///
+/// assert!(result.is_complete());
+/// assert_eq!(result.path.last(), Some(&Coordinate::new(40, 0, -20)));
+/// # Some(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HierarchicalPathfinder {
+    chunk_size: NonZero<u32>,
+    min_cost: u64,
+    max_expanded: Option<usize>,
+    faces: BTreeMap<(ChunkCoordinate, usize), Vec<Crossing>>,
+    chunks: BTreeMap<ChunkCoordinate, Chunk>,
+}
+
+/// Entrance pair on the face between a chunk and its neighbour along the positive axis.
+#[derive(Debug, Clone)]
+struct Crossing {
+    inner: Coordinate,
+    outer: Coordinate,
+    forward: Option<u64>,
+    backward: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Chunk {
+    entrances: Vec<Coordinate>,
+    links: BTreeMap<Coordinate, Vec<Link>>,
+}
+
+#[derive(Debug, Clone)]
+struct Link {
+    cost: u64,
+    path: Vec<Coordinate>,
+}
+
+struct Node {
+    coordinate: Coordinate,
+    cost: u64,
+    parent: Option<(usize, Vec<Coordinate>)>,
+    closed: bool,
+}
+
+impl HierarchicalPathfinder {
+    /// Creates a new `HierarchicalPathfinder` with chunks of the given size.
+    pub fn new(chunk_size: NonZero<u32>) -> Self {
+        Self {
+            chunk_size,
+            min_cost: 1,
+            max_expanded: None,
+            faces: BTreeMap::new(),
+            chunks: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the lowest cost a single move can have. Defaults to `1`.
+    pub fn min_cost(mut self, min_cost: u64) -> Self {
+        self.min_cost = min_cost;
+        self
+    }
+
+    /// Limits the amount of entrances the chunk-level search may expand.
+    pub fn max_expanded(mut self, max_expanded: usize) -> Self {
+        self.max_expanded = Some(max_expanded);
+        self
+    }
+
+    /// Returns the chunk `Region` containing `coordinate`.
+    ///
+    /// Chunks at the limits of the `Coordinate` range are cut off at `Coordinate::MIN` and
+    /// `Coordinate::MAX`. Returns `None` if the chunk's `Region` can't be represented.
+    pub fn chunk_of(&self, coordinate: &Coordinate) -> Option<Region> {
+        self.chunk_region(self.chunk_index(coordinate))
+    }
+
+    /// Discards everything cached about the chunk containing `coordinate`.
+    ///
+    /// Call this whenever the cost of moving into or out of `coordinate` changes.
+    pub fn invalidate(&mut self, coordinate: &Coordinate) {
+        let index = self.chunk_index(coordinate);
+        self.chunks.remove(&index);
+        for axis in 0..3 {
+            self.faces.remove(&(index, axis));
+            if let Some(previous) = step(index, axis, -1) {
+                self.faces.remove(&(previous, axis));
+                self.chunks.remove(&previous);
+            }
+            if let Some(next) = step(index, axis, 1) {
+                self.chunks.remove(&next);
+            }
+        }
+    }
+
+    /// Discards all cached chunks.
+    pub fn clear(&mut self) {
+        self.faces.clear();
+        self.chunks.clear();
+    }
+
+    /// Searches for a path from `start` to `goal`.
+    ///
+    /// `cost` is called with the current and the next `Coordinate` of a move and returns the cost
+    /// of that move, or `None` if the move is impossible. It must keep returning the same values
+    /// for a chunk until that chunk is invalidated.
+    ///
+    /// If the goal can't be reached, the returned path leads to the entrance closest to the goal.
+    pub fn find<F>(&mut self, start: Coordinate, goal: Coordinate, mut cost: F) -> PathResult
+    where
+        F: FnMut(&Coordinate, &Coordinate) -> Option<u64>,
+    {
+        let start_chunk = self.chunk_index(&start);
+        let goal_chunk = self.chunk_index(&goal);
+
+        let min_cost = self.min_cost;
+        let heuristic =
+            |coordinate: &Coordinate| manhattan(coordinate, &goal).saturating_mul(min_cost);
+        let mut nodes = vec![Node {
+            coordinate: start.to_owned(),
+            cost: 0,
+            parent: None,
+            closed: false,
+        }];
+        let mut indices = BTreeMap::from([(start, 0)]);
+        let mut open = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+        let mut closest = 0;
+        let mut expanded = 0;
+
+        let status = loop {
+            let Some(Reverse((_, node_cost, index))) = open.pop() else {
+                break PathStatus::Unreachable;
+            };
+            if nodes[index].closed || node_cost > nodes[index].cost {
+                continue;
+            }
+            if heuristic(&nodes[index].coordinate) < heuristic(&nodes[closest].coordinate) {
+                closest = index;
+            }
+            if nodes[index].coordinate == goal {
+                closest = index;
+                break PathStatus::Found;
+            }
+            if self.max_expanded.is_some_and(|max| expanded >= max) {
+                break PathStatus::BudgetExhausted;
+            }
+
+            nodes[index].closed = true;
+            expanded += 1;
+
+            let current = nodes[index].coordinate.to_owned();
+            let chunk = self.chunk_index(&current);
+            let mut links = self
+                .chunk(chunk, &mut cost)
+                .links
+                .get(&current)
+                .cloned()
+                .unwrap_or_default();
+            if index == 0 {
+                let entrances = self.chunk(start_chunk, &mut cost).entrances.to_owned();
+                for entrance in entrances.iter().filter(|&entrance| *entrance != start) {
+                    links.extend(self.link_within_chunk(&start, entrance, &mut cost));
+                }
+            }
+            if chunk == goal_chunk {
+                links.extend(self.link_within_chunk(&current, &goal, &mut cost));
+            }
+
+            for link in links {
+                let Some(next) = link.path.last().cloned() else {
+                    continue;
+                };
+                let next_cost = nodes[index].cost.saturating_add(link.cost);
+                let next_index = *indices.entry(next).or_insert_with(|| {
+                    nodes.push(Node {
+                        coordinate: next.to_owned(),
+                        cost: u64::MAX,
+                        parent: None,
+                        closed: false,
+                    });
+                    nodes.len() - 1
+                });
+                let node = &mut nodes[next_index];
+                if node.closed || next_cost >= node.cost {
+                    continue;
+                }
+                node.cost = next_cost;
+                node.parent = Some((index, link.path));
+                open.push(Reverse((
+                    next_cost.saturating_add(heuristic(&next)),
+                    next_cost,
+                    next_index,
+                )));
+            }
+        };
+
+        let mut segments = Vec::new();
+        let mut current = closest;
+        while let Some((parent, path)) = &nodes[current].parent {
+            segments.push(path);
+            current = *parent;
+        }
+        let mut path = vec![start];
+        for segment in segments.into_iter().rev() {
+            path.extend(segment.iter().skip(1).cloned());
+        }
+
+        PathResult {
+            path,
+            cost: nodes[closest].cost,
+            status,
+            expanded,
+        }
+    }
+
+    fn chunk_index(&self, coordinate: &Coordinate) -> ChunkCoordinate {
+        WorldCoordinate::new(*coordinate).to_chunk(self.chunk_size)
+    }
+
+    /// Returns the coordinate at `offset` from the chunk's origin, which may not be representable.
+    ///
+    /// Unlike [`ChunkCoordinate::to_world`], this also works for the cells of the chunk at
+    /// `Coordinate::MIN`, whose origin lies outside of the `Coordinate` range.
+    fn chunk_coordinate(&self, index: ChunkCoordinate, offset: [usize; 3]) -> Option<Coordinate> {
+        let size = space::chunk_size(self.chunk_size);
+        let index: [CoordinateType; 3] = index.into_inner().into();
+        let field = |axis: usize| {
+            let offset = i64::try_from(offset[axis]).ok()?;
+            CoordinateType::try_from(i64::from(index[axis]) * size + offset).ok()
+        };
+        Some(Coordinate::new(field(0)?, field(1)?, field(2)?))
+    }
+
+    /// Returns the representable part of the chunk, or `None` if there is none.
+    fn chunk_region(&self, index: ChunkCoordinate) -> Option<Region> {
+        let size = space::chunk_size(self.chunk_size);
+        let clamp = |n: i64| {
+            let n = n.clamp(CoordinateType::MIN.into(), CoordinateType::MAX.into());
+            CoordinateType::try_from(n).ok()
+        };
+        let index: [CoordinateType; 3] = index.into_inner().into();
+        let min = index.map(|index| clamp(i64::from(index) * size));
+        let max = index.map(|index| clamp(i64::from(index) * size + size - 1));
+        let region = Region::from_corners(
+            Coordinate::new(min[0]?, min[1]?, min[2]?),
+            Coordinate::new(max[0]?, max[1]?, max[2]?),
+        );
+        region.ok().filter(|region| !region.is_empty())
+    }
+
+    /// Finds a path from `from` to `to` that stays within the chunk containing both.
+    fn link_within_chunk<F>(&self, from: &Coordinate, to: &Coordinate, cost: &mut F) -> Option<Link>
+    where
+        F: FnMut(&Coordinate, &Coordinate) -> Option<u64>,
+    {
+        let region = self.chunk_of(from)?;
+        let result = AStar::new(Connectivity::Six).min_cost(self.min_cost).find(
+            from.to_owned(),
+            to.to_owned(),
+            |a, b| match region.contains(b) {
+                true => cost(a, b),
+                false => None,
+            },
+        );
+        result.is_complete().then_some(Link {
+            cost: result.cost,
+            path: result.path,
+        })
+    }
+
+    /// Returns the cached chunk at `index`, building it first if necessary.
+    fn chunk<F>(&mut self, index: ChunkCoordinate, cost: &mut F) -> &Chunk
+    where
+        F: FnMut(&Coordinate, &Coordinate) -> Option<u64>,
+    {
+        if !self.chunks.contains_key(&index) {
+            let chunk = self.build_chunk(index, cost);
+            self.chunks.insert(index, chunk);
+        }
+        &self.chunks[&index]
+    }
+
+    fn build_chunk<F>(&mut self, index: ChunkCoordinate, cost: &mut F) -> Chunk
+    where
+        F: FnMut(&Coordinate, &Coordinate) -> Option<u64>,
+    {
+        let mut chunk = Chunk::default();
+        let add_entrance =
+            |chunk: &mut Chunk, entrance: &Coordinate, to: &Coordinate, cost: Option<u64>| {
+                let links = chunk.links.entry(*entrance).or_insert_with(|| {
+                    chunk.entrances.push(entrance.to_owned());
+                    Vec::new()
+                });
+                if let Some(cost) = cost {
+                    links.push(Link {
+                        cost,
+                        path: vec![entrance.to_owned(), to.to_owned()],
+                    });
+                }
+            };
+
+        for axis in 0..3 {
+            for crossing in self.face(index, axis, cost).to_owned() {
+                add_entrance(
+                    &mut chunk,
+                    &crossing.inner,
+                    &crossing.outer,
+                    crossing.forward,
+                );
+            }
+            let Some(previous) = step(index, axis, -1) else {
+                continue;
+            };
+            for crossing in self.face(previous, axis, cost).to_owned() {
+                add_entrance(
+                    &mut chunk,
+                    &crossing.outer,
+                    &crossing.inner,
+                    crossing.backward,
+                );
+            }
+        }
+
+        for from in &chunk.entrances {
+            for to in chunk.entrances.iter().filter(|&to| to != from) {
+                if let Some(link) = self.link_within_chunk(from, to, cost) {
+                    chunk.links.entry(*from).or_default().push(link);
+                }
+            }
+        }
+        chunk
+    }
+
+    /// Returns the entrances on the face between the chunk at `index` and its neighbour along `axis`.
+    fn face<F>(&mut self, index: ChunkCoordinate, axis: usize, cost: &mut F) -> &[Crossing]
+    where
+        F: FnMut(&Coordinate, &Coordinate) -> Option<u64>,
+    {
+        if !self.faces.contains_key(&(index, axis)) {
+            let face = self.build_face(index, axis, cost);
+            self.faces.insert((index, axis), face);
+        }
+        &self.faces[&(index, axis)]
+    }
+
+    fn build_face<F>(&self, index: ChunkCoordinate, axis: usize, cost: &mut F) -> Vec<Crossing>
+    where
+        F: FnMut(&Coordinate, &Coordinate) -> Option<u64>,
+    {
+        let size = usize::try_from(space::chunk_size(self.chunk_size))
+            .expect("chunk sizes fit into a `usize`");
+        let mut direction = [0; 3];
+        direction[axis] = 1;
+
+        let crossings: Vec<Option<Crossing>> = (0..size * size)
+            .map(|cell| {
+                let mut offset = [0; 3];
+                offset[axis] = size - 1;
+                offset[(axis + 1) % 3] = cell % size;
+                offset[(axis + 2) % 3] = cell / size;
+                let inner = self.chunk_coordinate(index, offset)?;
+                let outer = checked_offset(&inner, direction)?;
+                let forward = cost(&inner, &outer);
+                let backward = cost(&outer, &inner);
+                (forward.is_some() || backward.is_some()).then_some(Crossing {
+                    inner,
+                    outer,
+                    forward,
+                    backward,
+                })
+            })
+            .collect();
+
+        let mut seen = vec![false; crossings.len()];
+        let mut entrances = Vec::new();
+        for first in 0..crossings.len() {
+            let Some(crossing) = &crossings[first] else {
+                continue;
+            };
+            if seen[first] {
+                continue;
+            }
+
+            entrances.push(crossing.to_owned());
+            seen[first] = true;
+            let mut queue = VecDeque::from([first]);
+            while let Some(cell) = queue.pop_front() {
+                let (u, v) = (cell % size, cell / size);
+                let neighbors = [
+                    (u > 0).then(|| cell - 1),
+                    (u + 1 < size).then(|| cell + 1),
+                    (v > 0).then(|| cell - size),
+                    (v + 1 < size).then(|| cell + size),
+                ];
+                for neighbor in neighbors.into_iter().flatten() {
+                    if crossings[neighbor].is_some() && !seen[neighbor] {
+                        seen[neighbor] = true;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        entrances
+    }
+}
+
+/// Returns the chunk `amount` chunks away along `axis`, or `None` if it lies outside of the
+/// `ChunkCoordinate` range.
+fn step(index: ChunkCoordinate, axis: usize, amount: CoordinateType) -> Option<ChunkCoordinate> {
+    let mut fields: [CoordinateType; 3] = index.into_inner().into();
+    fields[axis] = fields[axis].checked_add(amount)?;
+    Some(ChunkCoordinate::new(fields.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pathfinder() -> HierarchicalPathfinder {
+        HierarchicalPathfinder::new(NonZero::new(4).unwrap())
+    }
+
+    fn is_valid(path: &[Coordinate]) -> bool {
+        path.windows(2)
+            .all(|pair| manhattan(&pair[0], &pair[1]) == 1)
+    }
+
+    #[test]
+    fn open_floor() {
+        let floor = |_: &Coordinate, to: &Coordinate| (to.y == 0).then_some(1);
+        let result = pathfinder().find(Coordinate::new(1, 0, 1), Coordinate::new(-13, 0, 9), floor);
+        assert!(result.is_complete());
+        assert!(is_valid(&result.path));
+        assert_eq!(result.cost, result.path.len() as u64 - 1);
+        assert_eq!(result.path.last(), Some(&Coordinate::new(-13, 0, 9)));
+    }
+
+    #[test]
+    fn same_chunk() {
+        let mut pathfinder = pathfinder();
+        let chunk = pathfinder.chunk_of(&Coordinate::splat(0)).unwrap();
+        assert_eq!(chunk.position(), &Coordinate::splat(0));
+        let start = chunk.position().to_owned();
+        let goal = Coordinate::new(start.x + 3, start.y, start.z + 2);
+        let result = pathfinder.find(start, goal, |_, to| (to.y == 0).then_some(1));
+        assert!(result.is_complete());
+        assert_eq!(result.cost, 5);
+    }
+
+    #[test]
+    fn unreachable() {
+        let floor = |_: &Coordinate, to: &Coordinate| {
+            (to.y == 0 && to.x.abs() < 10 && to.z.abs() < 10).then_some(1)
+        };
+        let result = pathfinder().find(Coordinate::splat(0), Coordinate::new(20, 0, 0), floor);
+        assert_eq!(result.status, PathStatus::Unreachable);
+        assert!(is_valid(&result.path));
+    }
+
+    #[test]
+    fn invalidate() {
+        let mut pathfinder = pathfinder();
+        let goal = Coordinate::new(12, 0, 0);
+        let floor =
+            |_: &Coordinate, to: &Coordinate| (to.y == 0 && to.z == 0 && to.x >= 0).then_some(1);
+        assert!(pathfinder
+            .find(Coordinate::splat(0), goal.to_owned(), floor)
+            .is_complete());
+
+        let blocked = |_: &Coordinate, to: &Coordinate| {
+            (to.y == 0 && to.z == 0 && to.x >= 0 && to.x != 6).then_some(1)
+        };
+        pathfinder.invalidate(&Coordinate::new(6, 0, 0));
+        let result = pathfinder.find(Coordinate::splat(0), goal, blocked);
+        assert_eq!(result.status, PathStatus::Unreachable);
+    }
+
+    #[test]
+    fn coordinate_limits() {
+        let start = Coordinate::new(CoordinateType::MAX - 5, 0, 0);
+        let result = pathfinder().find(
+            start,
+            Coordinate::new(CoordinateType::MAX, 0, 0),
+            |_, to| (to.y == 0 && to.z == 0).then_some(1),
+        );
+        assert!(result.is_complete());
+        assert_eq!(result.cost, 5);
+    }
+
+    #[test]
+    fn chunks_at_limits() {
+        let pathfinder = HierarchicalPathfinder::new(NonZero::new(3).unwrap());
+        let chunk = pathfinder.chunk_of(&Coordinate::MIN).unwrap();
+        assert_eq!(chunk.position(), &Coordinate::MIN);
+        assert_eq!(chunk.extents(), [2, 2, 2]);
+
+        let chunk = pathfinder.chunk_of(&Coordinate::MAX).unwrap();
+        assert_eq!(
+            chunk.position(),
+            &Coordinate::splat(CoordinateType::MAX - 1)
+        );
+        assert_eq!(chunk.extents(), [2, 2, 2]);
+
+        let chunk = pathfinder.chunk_of(&Coordinate::splat(-1)).unwrap();
+        assert!(chunk.contains(&Coordinate::splat(-1)));
+        assert!(!chunk.contains(&Coordinate::splat(0)));
+    }
+
+    #[test]
+    fn chunks_match_world_coordinates() {
+        let chunk_size = NonZero::new(5).unwrap();
+        let pathfinder = HierarchicalPathfinder::new(chunk_size);
+        for coordinate in [Coordinate::new(-7, 3, 12), Coordinate::splat(4)] {
+            let (chunk, _) = WorldCoordinate::new(coordinate).split(chunk_size);
+            let origin = chunk.origin(chunk_size).unwrap();
+            assert_eq!(pathfinder.chunk_index(&coordinate), chunk);
+            assert_eq!(
+                pathfinder.chunk_of(&coordinate).unwrap().position(),
+                origin.as_inner()
+            );
+        }
+    }
+
+    #[test]
+    fn unit_chunks_at_limits() {
+        let mut pathfinder = HierarchicalPathfinder::new(NonZero::new(1).unwrap());
+        let floor = |_: &Coordinate, to: &Coordinate| {
+            (to.y == CoordinateType::MIN && to.z == CoordinateType::MAX).then_some(1)
+        };
+        let start = Coordinate::new(
+            CoordinateType::MIN,
+            CoordinateType::MIN,
+            CoordinateType::MAX,
+        );
+        let goal = Coordinate::new(start.x + 3, start.y, start.z);
+        let result = pathfinder.find(start, goal, floor);
+        assert!(result.is_complete());
+        assert_eq!(result.cost, 3);
+
+        pathfinder.invalidate(&start);
+        assert!(pathfinder.find(start, goal, floor).is_complete());
+    }
+}
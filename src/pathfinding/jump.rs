@@ -0,0 +1,271 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+use crate::{connectivity::checked_offset, prelude::*, region::Region};
+
+/// Successors of jump point search, computed lazily and cached for the duration of one search.
+///
+/// Instead of stopping at the next `Coordinate`, every move is repeated until a jump point is
+/// reached. A `Coordinate` is a jump point if it's the goal, if it has a forced neighbour, or if a
+/// jump along one of the moves that may follow the current one in the canonical order finds a
+/// jump point.
+///
+/// The canonical order sorts the moves of a shortest path in open space: corners before edges
+/// before faces for `TwentySix`, the `xy`, `xz` and `yz` edges before faces for `Eighteen` and `x`
+/// before `y` before `z` for `Six`. A neighbour is forced if the path to it through the current
+/// `Coordinate` turns against the canonical order, isn't cut short by a single move from the
+/// previous `Coordinate`, and every canonical path of two moves from there is blocked.
+///
+/// The start expands every move, all other jump points only the move they were reached by, the
+/// moves following it and their forced neighbours.
+pub(super) struct JumpPoints<'a, F> {
+    moves: &'static [[CoordinateType; 3]],
+    /// Bit set of the moves that may follow each move in the canonical order.
+    followers: Vec<u32>,
+    /// Index of the opposite of each move.
+    opposites: Vec<usize>,
+    /// Bit sets of the first moves of the canonical detours around each pair of moves that turns
+    /// against the canonical order, or `None` if the pair doesn't.
+    detours: Vec<Vec<Option<u32>>>,
+    bounds: &'a Region,
+    goal: Coordinate,
+    cost: F,
+    entry_costs: BTreeMap<Coordinate, Option<u64>>,
+    /// Bit set of the moves leading to a passable `Coordinate`, for every `Coordinate`.
+    passable: BTreeMap<Coordinate, u32>,
+    jumps: BTreeMap<(Coordinate, usize), Option<(Coordinate, u64)>>,
+}
+
+impl<'a, F> JumpPoints<'a, F>
+where
+    F: FnMut(&Coordinate, &Coordinate) -> Option<u64>,
+{
+    pub(super) fn new(
+        connectivity: Connectivity,
+        bounds: &'a Region,
+        goal: Coordinate,
+        cost: F,
+    ) -> Self {
+        let moves = connectivity.offsets();
+        let index_of =
+            |offset: [CoordinateType; 3]| moves.iter().position(|&other| other == offset);
+        let bits = |offsets: &mut dyn Iterator<Item = usize>| {
+            offsets.fold(0u32, |bits, index| bits | 1 << index)
+        };
+        let canonical = |first: [CoordinateType; 3], second: [CoordinateType; 3]| {
+            first == second || follows(connectivity, first, second)
+        };
+
+        let followers = moves
+            .iter()
+            .map(|&offset| {
+                bits(
+                    &mut (0..moves.len())
+                        .filter(|&next| follows(connectivity, offset, moves[next])),
+                )
+            })
+            .collect();
+        let opposites = moves
+            .iter()
+            .map(|&offset| {
+                index_of(offset.map(|n| -n)).expect("the moves of a `Connectivity` are symmetric")
+            })
+            .collect();
+        let mut detours = vec![vec![None; moves.len()]; moves.len()];
+        for (first, &offset) in moves.iter().enumerate() {
+            for (second, &next) in moves.iter().enumerate() {
+                let sum = [
+                    offset[0] + next[0],
+                    offset[1] + next[1],
+                    offset[2] + next[2],
+                ];
+                if sum == [0; 3] || index_of(sum).is_some() || canonical(offset, next) {
+                    continue;
+                }
+                detours[first][second] = Some(bits(&mut (0..moves.len()).filter(|&detour| {
+                    let [x, y, z] = moves[detour];
+                    let rest = [sum[0] - x, sum[1] - y, sum[2] - z];
+                    index_of(rest).is_some_and(|rest| canonical(moves[detour], moves[rest]))
+                })));
+            }
+        }
+
+        Self {
+            moves,
+            followers,
+            opposites,
+            detours,
+            bounds,
+            goal,
+            cost,
+            entry_costs: BTreeMap::new(),
+            passable: BTreeMap::new(),
+            jumps: BTreeMap::new(),
+        }
+    }
+
+    /// Pushes the jump points reached from the jump point `from` along with the cost to get
+    /// there, `parent` being the jump point `from` was reached from.
+    pub(super) fn successors(
+        &mut self,
+        from: &Coordinate,
+        parent: Option<&Coordinate>,
+        successors: &mut Vec<(Coordinate, u64)>,
+    ) {
+        let directions = match parent {
+            Some(parent) => {
+                let direction = self.direction(parent, from);
+                let previous = checked_offset(from, self.moves[self.opposites[direction]])
+                    .expect("jumps only pass through valid coordinates");
+                1 << direction | self.followers[direction] | self.forced(&previous, from, direction)
+            }
+            None => u32::MAX,
+        };
+        for direction in 0..self.moves.len() {
+            if directions & 1 << direction != 0 {
+                successors.extend(self.jump(*from, direction));
+            }
+        }
+    }
+
+    /// Repeats the move `direction` from `from` until a jump point is reached.
+    ///
+    /// Returns `None` if an obstacle or the bounds are hit first.
+    fn jump(&mut self, from: Coordinate, direction: usize) -> Option<(Coordinate, u64)> {
+        let mut visited = Vec::new();
+        let mut current = from;
+        let mut cost = 0u64;
+        let jump = loop {
+            if let Some(&jump) = self.jumps.get(&(current, direction)) {
+                break jump.map(|(target, rest)| (target, cost.saturating_add(rest)));
+            }
+            visited.push((current, cost));
+
+            let Some(next) = checked_offset(&current, self.moves[direction]) else {
+                break None;
+            };
+            let Some(step) = self.entry_cost(&current, &next) else {
+                break None;
+            };
+            cost = cost.saturating_add(step);
+            if self.is_jump_point(&current, &next, direction) {
+                break Some((next, cost));
+            }
+            current = next;
+        };
+
+        for (coordinate, cost_so_far) in visited {
+            let rest = jump.map(|(target, cost)| (target, cost - cost_so_far));
+            self.jumps.insert((coordinate, direction), rest);
+        }
+        jump
+    }
+
+    /// Returns `true` if moving from `previous` to `current` along `direction` has to stop at
+    /// `current`.
+    fn is_jump_point(
+        &mut self,
+        previous: &Coordinate,
+        current: &Coordinate,
+        direction: usize,
+    ) -> bool {
+        if *current == self.goal || self.forced(previous, current, direction) != 0 {
+            return true;
+        }
+
+        let followers = self.followers[direction];
+        (0..self.moves.len()).any(|follower| {
+            followers & 1 << follower != 0 && self.jump(*current, follower).is_some()
+        })
+    }
+
+    /// Returns the bit set of the forced neighbours of `current`, reached from `previous` along
+    /// `direction`.
+    fn forced(&mut self, previous: &Coordinate, current: &Coordinate, direction: usize) -> u32 {
+        let around_previous = self.passable_neighbours(previous);
+        let around_current = self.passable_neighbours(current);
+        (0..self.moves.len())
+            .filter(|&next| {
+                around_current & 1 << next != 0
+                    && self.detours[direction][next]
+                        .is_some_and(|detours| around_previous & detours == 0)
+            })
+            .fold(0, |forced, next| forced | 1 << next)
+    }
+
+    /// Returns the bit set of the moves from `coordinate` to a passable `Coordinate`.
+    fn passable_neighbours(&mut self, coordinate: &Coordinate) -> u32 {
+        if let Some(&passable) = self.passable.get(coordinate) {
+            return passable;
+        }
+
+        let mut passable = 0;
+        for (index, &offset) in self.moves.iter().enumerate() {
+            let next = checked_offset(coordinate, offset);
+            if next.is_some_and(|next| self.entry_cost(coordinate, &next).is_some()) {
+                passable |= 1 << index;
+            }
+        }
+        self.passable.insert(*coordinate, passable);
+        passable
+    }
+
+    /// Returns the cost of moving from `from` to `to`, which is assumed to only depend on `to`.
+    fn entry_cost(&mut self, from: &Coordinate, to: &Coordinate) -> Option<u64> {
+        if !self.bounds.contains(to) {
+            return None;
+        }
+        *self
+            .entry_costs
+            .entry(*to)
+            .or_insert_with(|| (self.cost)(from, to))
+    }
+
+    /// Returns the index of the move a jump from `from` to `to` repeats.
+    fn direction(&self, from: &Coordinate, to: &Coordinate) -> usize {
+        let offset = [
+            to.x.cmp(&from.x) as CoordinateType,
+            to.y.cmp(&from.y) as CoordinateType,
+            to.z.cmp(&from.z) as CoordinateType,
+        ];
+        self.moves
+            .iter()
+            .position(|&other| other == offset)
+            .expect("jumps follow a single move")
+    }
+}
+
+/// Returns `true` if `next` may follow `previous` in a canonical shortest path.
+fn follows(
+    connectivity: Connectivity,
+    previous: [CoordinateType; 3],
+    next: [CoordinateType; 3],
+) -> bool {
+    if next == previous {
+        return false;
+    }
+    let agrees = previous
+        .iter()
+        .zip(next)
+        .all(|(&previous, next)| previous * next >= 0);
+    match connectivity {
+        Connectivity::Six => rank_six(next) > rank_six(previous),
+        Connectivity::Eighteen => agrees && rank_eighteen(next) > rank_eighteen(previous),
+        Connectivity::TwentySix => previous
+            .iter()
+            .zip(next)
+            .all(|(&previous, next)| next == 0 || next == previous),
+    }
+}
+
+/// Orders the faces by their axis.
+fn rank_six(offset: [CoordinateType; 3]) -> usize {
+    offset.iter().position(|&n| n != 0).unwrap_or_default()
+}
+
+/// Orders the edges by their plane, `xy` first, and the faces after all edges.
+fn rank_eighteen(offset: [CoordinateType; 3]) -> usize {
+    match offset.iter().position(|&n| n == 0) {
+        Some(axis) if offset.iter().filter(|&&n| n != 0).count() == 2 => 2 - axis,
+        _ => 3,
+    }
+}
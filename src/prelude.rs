@@ -1,2 +1,15 @@
-pub use crate::coordinate::{Coordinate, CoordinateType, CoordinateTrait, UnsignedCoordinate, SignedCoordinate};
-pub use crate::region::Region;
+pub use crate::affine::{Offset, Position};
+pub use crate::connectivity::Connectivity;
+pub use crate::coordinate::{
+    Axis, BoolCoordinate, BoolCoordinate2, Coordinate, Coordinate2, Coordinate2Trait,
+    Coordinate3Math, CoordinateCompare, CoordinateField, CoordinateFormat, CoordinateMath,
+    CoordinateN, CoordinateTrait, CoordinateType, FormatStyle, FormattedCoordinate,
+    ParseCoordinateError, SignedCoordinate, SignedCoordinate2, TryFromCoordinateError,
+    UnsignedCoordinate, UnsignedCoordinate2,
+};
+pub use crate::flood_fill::FloodFill;
+pub use crate::pathfinding::{AStar, HierarchicalPathfinder};
+pub use crate::region::{
+    CoordinateSet, HyperRectangle, IterationOrder, Rectangle, Region, RegionMask,
+};
+pub use crate::space::{ChunkCoordinate, LocalCoordinate, SpaceCoordinate, WorldCoordinate};
@@ -1,13 +1,45 @@
-use std::num::NonZero;
+use num::{traits::ConstZero, CheckedAdd, NumCast, One, ToPrimitive, Zero};
+use thiserror::Error;
 
 use crate::prelude::*;
 
-/// `Region`'s size type.
-pub type SizeType = u8;
+pub use field::RegionField;
+pub use format::{FormattedRegion, ParseRegionError};
+pub use hyper_rectangle::HyperRectangle;
+pub use mask::{CoordinateSet, MaskIter, OutsideRegionError, RegionMask};
+pub use order::IterationOrder;
+pub use rectangle::Rectangle;
 
-/// Cube-shaped iterator of `Coordinate`s
+mod field;
+mod format;
+mod hyper_rectangle;
+mod mask;
+mod order;
+mod rectangle;
+
+/// Size type of a `Region<C>`, the unsigned counterpart of `C`'s field type.
+pub type RegionSize<C> = <<C as CoordinateMath>::FieldType as RegionField>::Size;
+
+/// `NonZero` version of [`RegionSize`].
+pub type NonZeroRegionSize<C> = <<C as CoordinateMath>::FieldType as RegionField>::NonZeroSize;
+
+/// Error returned when a [`Region`] can't be created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum RegionError {
+    #[error("The region extends past the largest coordinate on the {axis} axis.")]
+    Overflow { axis: Axis },
+    #[error("The region's extent on the {axis} axis doesn't fit into its size type.")]
+    TooLarge { axis: Axis },
+}
+
+/// Box-shaped iterator of coordinates, `Coordinate`s by default.
+///
+/// The iterator returns all coordinates from `position` to `position + extents - 1`, `x` first.
+/// Every axis may have its own extent, see [`Region::with_extents`], and the iteration order is
+/// configurable, see [`Region::order`].
 ///
-/// The iterator returns all `Coordinate`s from `position` to `position + size - 1`
+/// Any coordinate type works, as long as its field type implements [`RegionField`] and it can be
+/// converted into an array of its fields. The derived coordinate types of this crate all qualify.
 ///
 /// # Examples
 ///
@@ -30,109 +62,733 @@ pub type SizeType = u8;
 /// positions.push(Coordinate::new(8, 8, 8));
 ///
 /// let mut i = 0;
-/// let region = Region::new(Coordinate::splat(7), NonZero::<SizeType>::new(2)?);
+/// let region = Region::new(Coordinate::splat(7), NonZero::new(2)?);
 /// for pos in region {
 ///     println!("{i}");
 ///     assert_eq!(pos, positions[i]);
 ///     i += 1;
 /// }
+///
+/// // Local positions within a chunk.
+/// let chunk = Region::new(UnsignedCoordinate::<u8>::splat(0), NonZero::<u8>::new(16)?);
+/// assert_eq!(chunk.volume(), Some(4096));
+///
+/// // A world border far beyond 255 blocks.
+/// let border = Region::new(Coordinate::splat(-30_000_000), NonZero::new(60_000_000)?);
+/// assert_eq!(border.volume(), Some(216_000_000_000_000_000_000_000));
 /// # Some(())
 /// # }
 /// ```
 ///
-/// # Errors
-///
-/// Setting a `size` that is less than or equal to `0` will cause the program to crash.
-#[derive(PartialEq, Debug)]
-pub struct Region {
-    position: Coordinate,
-    size: SizeType,
-    offset: Coordinate,
+/// Regions created from plain sizes or corners may be empty, see [`Region::try_new`] and
+/// [`Region::from_corners`]. An empty `Region` doesn't yield any coordinates.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Region<C = Coordinate>
+where
+    C: CoordinateTrait,
+    C::FieldType: RegionField,
+{
+    position: C,
+    extents: [RegionSize<C>; 3],
+    order: IterationOrder,
+    steps: Option<[RegionSize<C>; 3]>,
     first_iteration: bool,
 }
 
-impl Region {
-    /// Creates a new Region
+impl<C> Region<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    /// The empty `Region`, which contains no coordinates.
+    ///
+    /// Every empty `Region` is equal to this one.
+    pub const EMPTY: Self = Self {
+        position: C::MIN,
+        extents: [RegionSize::<C>::ZERO; 3],
+        order: IterationOrder::XYZ,
+        steps: None,
+        first_iteration: false,
+    };
+
+    /// Creates a new cube-shaped Region
     ///
     /// - `position` corresponds to the starting position of the iterator.
-    /// - `size` detemines the range of the iterator. The range is exclusive. Must be larger than `0`.
-    pub fn new(position: Coordinate, size: NonZero<SizeType>) -> Self {
+    /// - `size` detemines the range of the iterator on every axis. The range is exclusive.
+    ///
+    /// The `Region` is cut off at the largest representable coordinate, so iterating it never
+    /// overflows. Use [`Region::try_new`] to reject such regions instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let region = Region::new(Coordinate::splat(CoordinateType::MAX - 1), NonZero::new(16).unwrap());
+    /// assert_eq!(region.extents(), [2, 2, 2]);
+    /// assert_eq!(region.last(), Some(Coordinate::MAX));
+    /// ```
+    pub fn new(position: C, size: NonZeroRegionSize<C>) -> Self {
+        Self::with_extents(position, [size; 3])
+    }
+
+    /// Creates a new Region with a separate size for every axis.
+    ///
+    /// Like [`Region::new`], every extent is cut off at the largest representable coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let extents = [NonZero::new(3).unwrap(), NonZero::new(1).unwrap(), NonZero::new(2).unwrap()];
+    /// let region = Region::with_extents(Coordinate::splat(0), extents);
+    /// assert_eq!(region.extents(), [3, 1, 2]);
+    /// assert_eq!(region.size(), None);
+    /// assert_eq!(region.count(), 6);
+    /// ```
+    pub fn with_extents(position: C, extents: [NonZeroRegionSize<C>; 3]) -> Self {
+        Self::from_extents(position, extents.map(Into::into))
+    }
+
+    /// Creates a new cube-shaped Region, which is empty if `size` is `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegionError::Overflow`] if the `Region` would extend past the largest
+    /// representable coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use argentum_game_coordinate_system::prelude::*;
+    /// use argentum_game_coordinate_system::region::RegionError;
+    ///
+    /// let region = Region::try_new(Coordinate::splat(0), 0).unwrap();
+    /// assert!(region.is_empty());
+    /// assert_eq!(region.count(), 0);
+    ///
+    /// let region = Region::try_new(Coordinate::new(0, CoordinateType::MAX - 1, 0), 2);
+    /// assert_eq!(region.unwrap().count(), 8);
+    ///
+    /// let region = Region::try_new(Coordinate::new(0, CoordinateType::MAX - 1, 0), 3);
+    /// assert_eq!(region, Err(RegionError::Overflow { axis: Axis::Y }));
+    /// ```
+    pub fn try_new(position: C, size: RegionSize<C>) -> Result<Self, RegionError> {
+        if size.is_zero() {
+            return Ok(Self::EMPTY);
+        }
+
+        let fields: [C::FieldType; 3] = position.into();
+        for (axis, field) in Axis::ALL.into_iter().zip(fields) {
+            field
+                .checked_add_size(size - RegionSize::<C>::one())
+                .ok_or(RegionError::Overflow { axis })?;
+        }
+        Ok(Self::from_extents(position, [size; 3]))
+    }
+
+    /// Creates the Region spanning from `min` to `max`, both inclusive.
+    ///
+    /// The `Region` is empty if `max` is smaller than `min` on any axis, like the intersection
+    /// of two disjoint boxes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegionError::TooLarge`] if an extent doesn't fit into the size type, which can
+    /// only happen if the corners lie on opposite limits of the field type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let region = Region::from_corners(Coordinate::new(0, 0, 0), Coordinate::new(2, 0, 1));
+    /// assert_eq!(region.map(|region| region.extents()), Ok([3, 1, 2]));
+    ///
+    /// let region = Region::from_corners(Coordinate::new(0, 0, 0), Coordinate::new(2, -1, 1));
+    /// assert_eq!(region, Ok(Region::EMPTY));
+    /// ```
+    pub fn from_corners(min: C, max: C) -> Result<Self, RegionError> {
+        let min_fields: [C::FieldType; 3] = min.into();
+        let max_fields: [C::FieldType; 3] = max.into();
+        let mut extents = [RegionSize::<C>::zero(); 3];
+        for axis in Axis::ALL {
+            let Some(distance) = min_fields[axis.index()].distance(max_fields[axis.index()]) else {
+                return Ok(Self::EMPTY);
+            };
+            extents[axis.index()] = distance
+                .checked_add(&RegionSize::<C>::one())
+                .ok_or(RegionError::TooLarge { axis })?;
+        }
+        Ok(Self::from_extents(min, extents))
+    }
+
+    /// Creates a new Region, cutting off every extent at the largest representable coordinate.
+    fn from_extents(position: C, mut extents: [RegionSize<C>; 3]) -> Self {
+        if extents.iter().any(Zero::is_zero) {
+            return Self::EMPTY;
+        }
+
+        let fields: [C::FieldType; 3] = position.into();
+        let max: [C::FieldType; 3] = C::MAX.into();
+        for ((extent, field), max) in extents.iter_mut().zip(fields).zip(max) {
+            // Only `None` if the whole range of the field type fits, which every extent does.
+            let limit = field
+                .distance(max)
+                .and_then(|distance| distance.checked_add(&RegionSize::<C>::one()));
+            if let Some(limit) = limit {
+                *extent = (*extent).min(limit);
+            }
+        }
+
         Self {
             position,
-            size: SizeType::from(size),
-            offset: Coordinate::new(0, 0, 0),
+            extents,
+            order: IterationOrder::XYZ,
+            steps: Some([RegionSize::<C>::zero(); 3]),
             first_iteration: true,
         }
     }
 
-    /// Returns the `Region`'s size.
-    pub fn size(&self) -> SizeType {
-        self.size
+    /// Sets the order the `Region` is iterated in, which [`Region::index_of`] and
+    /// [`Region::coordinate_at`] follow as well.
+    ///
+    /// Restarts the iteration. Empty regions are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let order = IterationOrder::ZYX.descending(Axis::Z);
+    /// let region = Region::new(Coordinate::splat(0), NonZero::new(2).unwrap()).order(order);
+    /// assert_eq!(region.coordinate_at(1), Some(Coordinate::new(0, 0, 0)));
+    /// assert_eq!(region.index_of(&Coordinate::new(0, 0, 1)), Some(0));
+    /// assert_eq!(region.last(), Some(Coordinate::new(1, 1, 0)));
+    /// ```
+    pub fn order(self, order: IterationOrder) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+
+        Self {
+            order,
+            ..self.rewound()
+        }
     }
-}
 
-impl Iterator for Region {
-    type Item = Coordinate;
+    /// Returns the order the `Region` is iterated in.
+    pub fn iteration_order(&self) -> IterationOrder {
+        self.order
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Returns a copy of this `Region` that iterates from the start again.
+    pub(crate) fn rewound(&self) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+
+        Self {
+            steps: Some([RegionSize::<C>::zero(); 3]),
+            first_iteration: true,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the `Region`'s starting position.
+    pub fn position(&self) -> &C {
+        &self.position
+    }
+
+    /// Returns the `Region`'s size, or `None` if its extents differ.
+    pub fn size(&self) -> Option<RegionSize<C>> {
+        let [x, y, z] = self.extents;
+        (x == y && y == z).then_some(x)
+    }
+
+    /// Returns the `Region`'s size on every axis.
+    pub fn extents(&self) -> [RegionSize<C>; 3] {
+        self.extents
+    }
+
+    /// Returns the amount of coordinates contained in the `Region`.
+    ///
+    /// Only returns `None` for 64 bit or wider field types, if the amount doesn't fit into a `u128`.
+    pub fn volume(&self) -> Option<u128> {
+        self.extents.iter().try_fold(1u128, |volume, extent| {
+            volume.checked_mul(extent.to_u128()?)
+        })
+    }
+
+    /// Returns the volume if it fits into a `usize`, for allocating one element per coordinate.
+    pub(crate) fn volume_usize(&self) -> Option<usize> {
+        usize::try_from(self.volume()?).ok()
+    }
+
+    /// Returns the amount of coordinates the iterator has yet to yield.
+    ///
+    /// Returns `None` if the amount doesn't fit into a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let mut region = Region::new(Coordinate::splat(0), NonZero::new(2).unwrap());
+    /// assert_eq!(region.len(), Some(8));
+    /// region.nth(4);
+    /// assert_eq!(region.len(), Some(3));
+    /// ```
+    pub fn len(&self) -> Option<usize> {
+        usize::try_from(self.remaining()?).ok()
+    }
+
+    /// Returns the amount of coordinates the iterator has yet to yield, or `None` if it doesn't
+    /// fit into a `u128`.
+    fn remaining(&self) -> Option<u128> {
         if self.first_iteration {
-            self.first_iteration = false;
-            return Some(self.position.to_owned());
+            return self.volume();
         }
+        let Some(steps) = self.steps else {
+            return Some(0);
+        };
+
+        let [fastest, middle, slowest] = self.order.axes().map(Axis::index);
+        let index = steps[slowest]
+            .to_u128()?
+            .checked_mul(self.extents[middle].to_u128()?)?
+            .checked_add(steps[middle].to_u128()?)?
+            .checked_mul(self.extents[fastest].to_u128()?)?
+            .checked_add(steps[fastest].to_u128()?)?;
+        self.volume()?.checked_sub(index)?.checked_sub(1)
+    }
+
+    /// Returns `true` if the `Region` contains no coordinates.
+    pub fn is_empty(&self) -> bool {
+        self.extents.iter().any(Zero::is_zero)
+    }
 
-        let size = CoordinateType::try_from(self.size()).unwrap_or_default();
+    /// Returns `true` if `coordinate` lies within the `Region`.
+    pub fn contains(&self, coordinate: &C) -> bool {
+        self.corners()
+            .is_some_and(|(min, max)| coordinate.cmpge(min).all() && coordinate.cmple(max).all())
+    }
+
+    /// Returns the position of `coordinate` in the `Region`'s iteration order.
+    ///
+    /// Returns `None` if `coordinate` lies outside of the `Region` or the position doesn't fit
+    /// into a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() {
+    /// #     test().unwrap();
+    /// # }
+    /// # fn test() -> Option<()> {
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let region = Region::new(Coordinate::splat(7), NonZero::new(2)?);
+    /// assert_eq!(region.index_of(&Coordinate::new(7, 7, 7)), Some(0));
+    /// assert_eq!(region.index_of(&Coordinate::new(8, 7, 7)), Some(1));
+    /// assert_eq!(region.index_of(&Coordinate::new(7, 8, 7)), Some(2));
+    /// assert_eq!(region.index_of(&Coordinate::new(9, 7, 7)), None);
+    /// # Some(())
+    /// # }
+    /// ```
+    pub fn index_of(&self, coordinate: &C) -> Option<usize> {
+        let offsets = self.offsets_of(coordinate)?;
+        let [fastest, middle, slowest] = self.order.axes().map(|axis| {
+            let step = self.steps_along(axis, offsets[axis.index()]);
+            (step, self.extents[axis.index()])
+        });
+        [middle, fastest]
+            .into_iter()
+            .try_fold(slowest.0.to_usize()?, |index, (step, extent)| {
+                let step = step.to_usize()?;
+                match index {
+                    0 => Some(step),
+                    _ => index.checked_mul(extent.to_usize()?)?.checked_add(step),
+                }
+            })
+    }
 
-        fn increment(n: &mut CoordinateType, size: CoordinateType) -> bool {
-            *n = (*n + 1) % size;
-            *n == 0
+    /// Returns the coordinate at `index` in the `Region`'s iteration order.
+    ///
+    /// Returns `None` if `index` is out of bounds or the coordinate can't be represented.
+    pub fn coordinate_at(&self, mut index: usize) -> Option<C> {
+        if self.is_empty() {
+            return None;
         }
 
-        let wrapped_x = increment(&mut self.offset.x, size);
-        let mut wrapped_y = false;
-        let mut wrapped_z = false;
+        let mut fields: [C::FieldType; 3] = self.position.into();
+        for axis in self.order.axes() {
+            let step = match self.extents[axis.index()].to_usize() {
+                Some(extent) => {
+                    let step = index % extent;
+                    index /= extent;
+                    step
+                }
+                None => core::mem::take(&mut index),
+            };
+            let offset = self.steps_along(axis, NumCast::from(step)?);
+            fields[axis.index()] = fields[axis.index()].checked_add_size(offset)?;
+        }
+        let [x, y, z] = fields;
+        (index == 0).then(|| C::new(x, y, z))
+    }
 
-        if wrapped_x {
-            wrapped_y = increment(&mut self.offset.y, size);
+    /// Converts between the offset from `position` on `axis` and the amount of steps the
+    /// iteration takes along it to get there.
+    fn steps_along(&self, axis: Axis, n: RegionSize<C>) -> RegionSize<C> {
+        match self.order.is_descending(axis) {
+            true => self.extents[axis.index()] - RegionSize::<C>::one() - n,
+            false => n,
         }
-        if wrapped_y {
-            wrapped_z = increment(&mut self.offset.z, size);
+    }
+
+    /// Returns the smallest and the largest coordinate of the `Region`, or `None` if it's empty.
+    pub(crate) fn corners(&self) -> Option<(C, C)> {
+        if self.is_empty() {
+            return None;
         }
-        match wrapped_z {
-            false => Some(self.position.to_owned() + self.offset.to_owned()),
-            true => None,
+
+        let fields: [C::FieldType; 3] = self.position.into();
+        let [x, y, z] = Axis::ALL.map(|axis| {
+            fields[axis.index()]
+                .checked_add_size(self.extents[axis.index()] - RegionSize::<C>::one())
+                .expect("extents are cut off at the largest coordinate")
+        });
+        Some((self.position, C::new(x, y, z)))
+    }
+
+    /// Returns the coordinate reached after taking `steps` along every axis.
+    fn coordinate_after(&self, steps: [RegionSize<C>; 3]) -> C {
+        let fields: [C::FieldType; 3] = self.position.into();
+        let [x, y, z] = Axis::ALL.map(|axis| {
+            fields[axis.index()]
+                .checked_add_size(self.steps_along(axis, steps[axis.index()]))
+                .expect("extents are cut off at the largest coordinate")
+        });
+        C::new(x, y, z)
+    }
+
+    /// Returns the offsets of `coordinate` from `position` if it lies within the `Region`.
+    fn offsets_of(&self, coordinate: &C) -> Option<[RegionSize<C>; 3]> {
+        let start: [C::FieldType; 3] = self.position.into();
+        let coordinate: [C::FieldType; 3] = (*coordinate).into();
+        let offset = |axis: usize| {
+            start[axis]
+                .distance(coordinate[axis])
+                .filter(|&offset| offset < self.extents[axis])
+        };
+        Some([offset(0)?, offset(1)?, offset(2)?])
+    }
+}
+
+impl Region {
+    /// Returns the `Rectangle` covered by this `Region` when projected along `axis`.
+    ///
+    /// The remaining two axes keep their order, like [`Coordinate::project`].
+    pub fn project(&self, axis: Axis) -> Rectangle {
+        let [a, b] = match axis {
+            Axis::X => [1, 2],
+            Axis::Y => [0, 2],
+            Axis::Z => [0, 1],
+        }
+        .map(|axis| self.extents[axis]);
+        Rectangle::from_extents(self.position.project(axis), [a, b])
+    }
+}
+
+impl<C> Iterator for Region<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first_iteration {
+            self.first_iteration = false;
+            return Some(self.coordinate_after([RegionSize::<C>::zero(); 3]));
+        }
+
+        let steps = self.steps.as_mut()?;
+        let mut wrapped = true;
+        for axis in self.order.axes().map(Axis::index) {
+            let n = &mut steps[axis];
+            *n = *n + RegionSize::<C>::one();
+            wrapped = *n == self.extents[axis];
+            if !wrapped {
+                break;
+            }
+            *n = RegionSize::<C>::zero();
+        }
+        if wrapped {
+            self.steps = None;
+            return None;
+        }
+
+        let steps = *steps;
+        Some(self.coordinate_after(steps))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.len() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
         }
     }
 }
 
 #[cfg(test)]
-mod tests {
-    use quickcheck::quickcheck;
+pub(crate) mod tests {
+    use core::num::NonZero;
 
     use super::*;
 
-    quickcheck! {
-        fn new(pos: Coordinate, size: NonZero<SizeType>) -> bool {
-            let result =  Region::new(pos.clone(), size);
+    /// Creates a cube-shaped region, shared by the tests of the modules built on `Region`.
+    pub(crate) fn region(position: Coordinate, size: u32) -> Region {
+        Region::new(position, NonZero::new(size).unwrap())
+    }
+
+    quickcheck::quickcheck! {
+        fn new(pos: (CoordinateType, CoordinateType, CoordinateType), size: NonZero<u32>) -> bool {
+            let pos = Coordinate::from(pos);
+            let result =  Region::new(pos, size);
+            let fields: [CoordinateType; 3] = pos.into();
+            let extent = |n: CoordinateType| match CoordinateType::MAX.abs_diff(n).checked_add(1) {
+                Some(limit) => size.get().min(limit),
+                None => size.get(),
+            };
             let expected = Region {
                 position: pos,
-                size: size.get(),
-                offset: Coordinate::new(0, 0, 0),
+                extents: fields.map(extent),
+                order: IterationOrder::XYZ,
+                steps: Some([0; 3]),
                 first_iteration: true,
             };
             result == expected
         }
     }
 
+    quickcheck::quickcheck! {
+        fn iteration_is_total(pos: (i8, i8, i8), extents: (u8, u8, u8)) -> bool {
+            let (x, y, z) = extents;
+            let extents = [x, y, z].map(|extent| NonZero::new(extent % 8 + 1).unwrap());
+            let region = Region::with_extents(SignedCoordinate::from(pos), extents);
+            let len = region.len();
+            len == Some(region.count())
+        }
+
+        fn iteration_near_max_is_total(distance: (u8, u8, u8), size: u32) -> bool {
+            let (x, y, z) = distance;
+            let distance = Coordinate::new(x.into(), y.into(), z.into()) % 4;
+            let region = Region::new(Coordinate::MAX - distance, NonZero::new(size % 8 + 1).unwrap());
+            let len = region.len();
+            region.clone().all(|coordinate| region.contains(&coordinate))
+                && len == Some(region.count())
+        }
+
+        fn len_counts_down(pos: (CoordinateType, CoordinateType, CoordinateType), size: u32, skip: usize) -> bool {
+            let mut region = Region::new(Coordinate::from(pos), NonZero::new(size % 4 + 1).unwrap());
+            let volume = region.len().unwrap();
+            let skip = skip % (volume + 1);
+            region.by_ref().take(skip).for_each(drop);
+            region.size_hint() == (volume - skip, Some(volume - skip))
+        }
+    }
+
     #[test]
-    #[should_panic]
     fn zero_size() {
-        let region = Region::new(Coordinate::splat(0), NonZero::<SizeType>::new(0).unwrap());
+        let region = Region::try_new(Coordinate::splat(0), 0).unwrap();
+        assert_eq!(region, Region::EMPTY);
+        assert!(region.is_empty());
+        assert_eq!(region.volume(), Some(0));
+        assert!(!region.contains(&Coordinate::MIN));
+        assert_eq!(region.index_of(&Coordinate::MIN), None);
+        assert_eq!(region.coordinate_at(0), None);
+        assert_eq!(region.count(), 0);
+    }
+
+    #[test]
+    fn try_new() {
+        let position = Coordinate::new(0, 0, CoordinateType::MAX);
+        assert!(Region::try_new(position, 1).is_ok());
+        assert_eq!(
+            Region::try_new(position, 2),
+            Err(RegionError::Overflow { axis: Axis::Z })
+        );
+        assert_eq!(
+            Region::try_new(Coordinate::splat(-1), 16),
+            Ok(Region::new(
+                Coordinate::splat(-1),
+                NonZero::new(16).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn from_corners() {
+        let region = Region::from_corners(Coordinate::new(-1, 4, 0), Coordinate::new(1, 4, 1));
+        let extents = [3, 1, 2].map(|extent| NonZero::new(extent).unwrap());
+        assert_eq!(
+            region,
+            Ok(Region::with_extents(Coordinate::new(-1, 4, 0), extents))
+        );
+        assert_eq!(
+            Region::from_corners(Coordinate::splat(1), Coordinate::splat(0)),
+            Ok(Region::EMPTY)
+        );
+        assert_eq!(
+            Region::from_corners(Coordinate::MIN, Coordinate::MAX),
+            Err(RegionError::TooLarge { axis: Axis::X })
+        );
+        let region = Region::from_corners(
+            SignedCoordinate::<i64>::new(0, i64::MIN, 0),
+            SignedCoordinate::new(0, i64::MAX - 1, 0),
+        );
+        assert_eq!(region.map(|region| region.extents()), Ok([1, u64::MAX, 1]));
+    }
+
+    #[test]
+    fn iteration_is_sorted() {
+        let region = Region::new(Coordinate::splat(-1), NonZero::new(3).unwrap());
+        let coordinates: Vec<_> = region.collect();
+        assert!(coordinates.windows(2).all(|pair| pair[0] < pair[1]));
+    }
 
-        let mut i = 0;
-        for pos in region {
-            println!("{pos}");
-            i += 1;
+    #[test]
+    fn orders() {
+        let extents = [3, 1, 2].map(|extent| NonZero::new(extent).unwrap());
+        let region = Region::with_extents(Coordinate::new(-1, 4, 0), extents);
+        let mut expected: Vec<_> = region.clone().collect();
+        expected.sort_by_key(|coordinate| (coordinate.x, coordinate.y, coordinate.z));
+
+        let axes = [Axis::X, Axis::Y, Axis::Z];
+        let permutations = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        for permutation in permutations {
+            let order = IterationOrder::new(permutation.map(|axis| axes[axis])).unwrap();
+            for descending in [None, Some(Axis::X), Some(Axis::Z)] {
+                let order = descending.map_or(order, |axis| order.descending(axis));
+                let region = region.clone().order(order);
+                assert_eq!(region.iteration_order(), order);
+                assert_eq!(region.len(), Some(6));
+
+                let mut coordinates = Vec::new();
+                for (index, coordinate) in region.clone().enumerate() {
+                    assert_eq!(region.index_of(&coordinate), Some(index));
+                    assert_eq!(region.coordinate_at(index), Some(coordinate));
+                    coordinates.push(coordinate);
+                }
+                coordinates.sort_by_key(|coordinate| (coordinate.x, coordinate.y, coordinate.z));
+                assert_eq!(coordinates, expected);
+            }
         }
-        assert!(i < 0, "Never even iterated!");
+
+        assert_eq!(IterationOrder::new([Axis::X, Axis::Y, Axis::X]), None);
+        let order = IterationOrder::YXZ.descending(Axis::Y);
+        assert_eq!(Region::<Coordinate>::EMPTY.order(order), Region::EMPTY);
+    }
+
+    #[test]
+    fn order_restarts_iteration() {
+        let mut region = Region::new(Coordinate::splat(0), NonZero::new(2).unwrap());
+        region.nth(3);
+        let region = region.order(IterationOrder::ZYX.descending(Axis::X));
+        assert_eq!(region.len(), Some(8));
+        assert_eq!(region.clone().next(), Some(Coordinate::new(1, 0, 0)));
+        assert_eq!(region.last(), Some(Coordinate::new(0, 1, 1)));
+    }
+
+    #[test]
+    fn unsigned() {
+        let region = Region::new(
+            UnsignedCoordinate::<u8>::new(0, 0, 250),
+            NonZero::new(6).unwrap(),
+        );
+        assert_eq!(region.size(), Some(6u8));
+        assert!(region.contains(&UnsignedCoordinate::new(5, 5, 255)));
+        assert!(!region.contains(&UnsignedCoordinate::new(6, 5, 255)));
+        assert_eq!(region.clone().count(), 216);
+        assert_eq!(
+            region.clone().last(),
+            Some(UnsignedCoordinate::new(5, 5, 255))
+        );
+        assert_eq!(
+            region.index_of(&UnsignedCoordinate::new(1, 0, 250)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn extents() {
+        let extents = [3, 1, 2].map(|extent| NonZero::new(extent).unwrap());
+        let region = Region::with_extents(Coordinate::new(-1, 4, 0), extents);
+        assert_eq!(region.volume(), Some(6));
+        assert!(region.contains(&Coordinate::new(1, 4, 1)));
+        assert!(!region.contains(&Coordinate::new(1, 5, 1)));
+        assert_eq!(region.project(Axis::Y).extents(), [3, 2]);
+        assert_eq!(region.coordinate_at(4), Some(Coordinate::new(0, 4, 1)));
+        assert_eq!(region.coordinate_at(6), None);
+        for (index, coordinate) in region.clone().enumerate() {
+            assert_eq!(region.index_of(&coordinate), Some(index));
+        }
+        assert_eq!(region.count(), 6);
+    }
+
+    #[test]
+    fn touching_max() {
+        let region = Region::new(
+            Coordinate::splat(CoordinateType::MAX - 1),
+            NonZero::new(2).unwrap(),
+        );
+        assert_eq!(region.clone().count(), 8);
+        assert_eq!(region.last(), Some(Coordinate::MAX));
+
+        let region = Region::new(
+            UnsignedCoordinate::<u8>::new(0, 0, 250),
+            NonZero::new(u8::MAX).unwrap(),
+        );
+        assert_eq!(region.extents(), [255, 255, 6]);
+        assert_eq!(region.len(), Some(255 * 255 * 6));
+        assert_eq!(region.count(), 255 * 255 * 6);
+    }
+
+    #[test]
+    fn large() {
+        let region = Region::new(
+            SignedCoordinate::<i64>::splat(i64::MIN),
+            NonZero::<u64>::MAX,
+        );
+        assert_eq!(region.volume(), None);
+        assert!(region.contains(&SignedCoordinate::splat(i64::MAX - 1)));
+        assert!(!region.contains(&SignedCoordinate::splat(i64::MAX)));
+        assert_eq!(
+            region.index_of(&SignedCoordinate::new(i64::MIN + 1, i64::MIN, i64::MIN)),
+            Some(1)
+        );
+        assert_eq!(
+            region.index_of(&SignedCoordinate::splat(i64::MIN + 1)),
+            None
+        );
+        assert_eq!(
+            region.coordinate_at(5),
+            Some(SignedCoordinate::new(i64::MIN + 5, i64::MIN, i64::MIN))
+        );
     }
 }
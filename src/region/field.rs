@@ -0,0 +1,62 @@
+use core::{
+    fmt::{Debug, Display, LowerHex},
+    hash::Hash,
+    num::NonZero,
+};
+
+use num::{traits::ConstZero, PrimInt, Unsigned};
+
+/// Field types of coordinates a `Region` can be built from.
+///
+/// Pairs every field type with the unsigned type of the same width, which can hold the distance
+/// between any two of its values.
+pub trait RegionField: Copy + Ord {
+    /// Type of a `Region`'s size.
+    type Size: PrimInt + Unsigned + ConstZero + Hash + Debug + Display + LowerHex;
+    /// `NonZero` version of `Size`, accepted by `Region::new`.
+    type NonZeroSize: Copy + Into<Self::Size>;
+
+    /// Returns `to - self`, or `None` if `to` is smaller than `self`.
+    fn distance(self, to: Self) -> Option<Self::Size>;
+
+    /// Returns `self + offset`, or `None` if the result can't be represented.
+    fn checked_add_size(self, offset: Self::Size) -> Option<Self>;
+}
+
+/// Implements `RegionField` for `$field`, adding sizes with `$add`.
+macro_rules! impl_region_field {
+    ($add:ident: $($field:ty => $size:ty),* $(,)?) => {
+        $(
+            impl RegionField for $field {
+                type Size = $size;
+                type NonZeroSize = NonZero<$size>;
+
+                fn distance(self, to: Self) -> Option<Self::Size> {
+                    (to >= self).then(|| to.abs_diff(self))
+                }
+
+                fn checked_add_size(self, offset: Self::Size) -> Option<Self> {
+                    self.$add(offset)
+                }
+            }
+        )*
+    };
+}
+
+impl_region_field!(checked_add_unsigned:
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+);
+
+impl_region_field!(checked_add:
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+);
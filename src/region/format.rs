@@ -0,0 +1,374 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+use super::{RegionField, RegionSize};
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ParseRegionError {
+    #[error(
+        "Expected \"(Region: <position>, <size>)\" or \"(Region: <position>, <x> <y> <z>)\", optionally followed by \", order: <order>\" before the closing parenthesis, which doesn't match at position {position}."
+    )]
+    Format { position: usize },
+    #[error("Invalid position: {0}")]
+    Position(ParseCoordinateError),
+    #[error("The size at position {position} isn't a number fitting the region's size type.")]
+    Size { position: usize },
+    #[error(
+        "The iteration order at position {position} isn't a permutation of the axes, like \"x z -y\"."
+    )]
+    Order { position: usize },
+}
+
+/// Formats the `Region` as `(Region: <position>, <size>)`, ignoring the iteration state.
+///
+/// Regions that aren't cubes are formatted as `(Region: <position>, <x> <y> <z>)` instead. An
+/// [`IterationOrder`] other than the default one is appended as `, order: <order>`, e.g.
+/// `(Region: <position>, <size>, order: x z -y)`.
+impl<C> Display for Region<C>
+where
+    C: CoordinateTrait,
+    C::FieldType: RegionField,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "(Region: {}, ", self.position)?;
+        match self.extents {
+            [x, y, z] if x == y && y == z => write!(f, "{x}")?,
+            [x, y, z] => write!(f, "{x} {y} {z}")?,
+        }
+        if self.order != IterationOrder::default() {
+            write!(f, ", order: {}", self.order)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// A `Region` formatted according to a [`CoordinateFormat`], created by
+/// [`Region::format_with`].
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedRegion<'a, C = Coordinate>
+where
+    C: CoordinateTrait,
+    C::FieldType: RegionField,
+{
+    region: &'a Region<C>,
+    format: CoordinateFormat,
+}
+
+impl<C> Region<C>
+where
+    C: CoordinateTrait,
+    C::FieldType: RegionField,
+{
+    /// Returns a value that formats this `Region` according to `format`.
+    ///
+    /// The format applies to the position, the size is only affected by
+    /// [`CoordinateFormat::hex`]. Regions that aren't cubes list their extents, as an array in
+    /// JSON. Like `Display`, an `IterationOrder` other than the default one is appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::NonZero;
+    ///
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let region = Region::new(Coordinate::new(1, 2, 3), NonZero::new(4).unwrap());
+    /// assert_eq!(region.format_with(CoordinateFormat::compact()).to_string(), "1 2 3, 4");
+    /// assert_eq!(
+    ///     region.format_with(CoordinateFormat::json()).to_string(),
+    ///     r#"{"position":{"x":1,"y":2,"z":3},"size":4}"#
+    /// );
+    ///
+    /// let extents = [NonZero::new(4).unwrap(), NonZero::new(1).unwrap(), NonZero::new(2).unwrap()];
+    /// let region = Region::with_extents(UnsignedCoordinate::<u8>::new(1, 2, 3), extents);
+    /// assert_eq!(region.format_with(CoordinateFormat::compact()).to_string(), "1 2 3, 4 1 2");
+    /// assert_eq!(
+    ///     region.format_with(CoordinateFormat::json()).to_string(),
+    ///     r#"{"position":{"x":1,"y":2,"z":3},"size":[4,1,2]}"#
+    /// );
+    /// ```
+    pub fn format_with(&self, format: CoordinateFormat) -> FormattedRegion<'_, C> {
+        FormattedRegion {
+            region: self,
+            format,
+        }
+    }
+}
+
+impl<C> Display for FormattedRegion<'_, C>
+where
+    C: CoordinateTrait,
+    C::FieldType: RegionField,
+    for<'a> FormattedCoordinate<'a, C>: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (open, separator, close) = match self.format.style {
+            FormatStyle::Verbose => ("(Region: ", ", ", ")"),
+            FormatStyle::Compact => ("", ", ", ""),
+            FormatStyle::Json => ("{\"position\":", ",\"size\":", "}"),
+        };
+        f.write_str(open)?;
+        FormattedCoordinate::new(&self.region.position, self.format).fmt(f)?;
+        f.write_str(separator)?;
+        let [x, y, z] = self.region.extents;
+        if x == y && y == z {
+            self.format.write_number(f, x)?;
+        } else {
+            let json = self.format.style == FormatStyle::Json;
+            f.write_str(if json { "[" } else { "" })?;
+            for (axis, extent) in self.region.extents.into_iter().enumerate() {
+                if axis > 0 {
+                    f.write_str(if json { "," } else { " " })?;
+                }
+                self.format.write_number(f, extent)?;
+            }
+            f.write_str(if json { "]" } else { "" })?;
+        }
+        if self.region.order != IterationOrder::default() {
+            match self.format.style {
+                FormatStyle::Json => write!(f, ",\"order\":\"{}\"", self.region.order)?,
+                FormatStyle::Verbose | FormatStyle::Compact => {
+                    write!(f, ", order: {}", self.region.order)?
+                }
+            }
+        }
+        f.write_str(close)
+    }
+}
+
+/// Parses the `Display` format. The position may be given in any format `Coordinate` accepts.
+///
+/// A size of `0` on any axis yields [`Region::EMPTY`].
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let region: Region = "(Region: 1 2 3, 4)".parse().unwrap();
+/// assert_eq!(region.to_string(), "(Region: (SignedCoordinate: 1, 2, 3), 4)");
+/// assert_eq!(region.to_string().parse(), Ok(region));
+///
+/// let region: Region = "(Region: 1 2 3, 4 1 2, order: z y -x)".parse().unwrap();
+/// assert_eq!(region.extents(), [4, 1, 2]);
+/// assert_eq!(region.iteration_order(), IterationOrder::ZYX.descending(Axis::X));
+/// ```
+impl<C> FromStr for Region<C>
+where
+    C: CoordinateTrait<Type = C>
+        + Copy
+        + Into<[C::FieldType; 3]>
+        + FromStr<Err = ParseCoordinateError>,
+    C::FieldType: RegionField,
+    RegionSize<C>: FromStr,
+{
+    type Err = ParseRegionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let position_of = |rest: &str| s.len() - rest.len();
+        let format_error = |position| ParseRegionError::Format { position };
+
+        let rest = s.trim_start();
+        let rest = rest
+            .strip_prefix('(')
+            .ok_or(format_error(position_of(rest)))?;
+        let rest = rest.trim_start();
+        let rest = rest
+            .strip_prefix("Region")
+            .ok_or(format_error(position_of(rest)))?;
+        let rest = rest.trim_start();
+        let rest = rest
+            .strip_prefix(':')
+            .ok_or(format_error(position_of(rest)))?;
+
+        let body_start = position_of(rest);
+        let rest = rest.trim_end();
+        let mut body = rest
+            .strip_suffix(')')
+            .ok_or(format_error(body_start + rest.len()))?;
+        let mut comma = body
+            .rfind(',')
+            .ok_or(format_error(body_start + body.len()))?;
+
+        let mut order = IterationOrder::default();
+        let last = &body[comma + 1..];
+        if let Some(order_spec) = last.trim_start().strip_prefix("order") {
+            let order_spec = order_spec.trim_start();
+            let order_spec = order_spec
+                .strip_prefix(':')
+                .ok_or(format_error(body_start + body.len() - order_spec.len()))?;
+            let order_start = body_start + body.len() - order_spec.len();
+            order = IterationOrder::parse(order_spec).ok_or(ParseRegionError::Order {
+                position: order_start,
+            })?;
+            body = &body[..comma];
+            comma = body
+                .rfind(',')
+                .ok_or(format_error(body_start + body.len()))?;
+        }
+
+        let position = body[..comma]
+            .parse()
+            .map_err(|error: ParseCoordinateError| {
+                ParseRegionError::Position(error.offset(body_start))
+            })?;
+
+        let mut extents = [None; 3];
+        let mut count = 0;
+        let mut rest = &body[comma + 1..];
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+            let start = body_start + body.len() - rest.len();
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let extent = extents.get_mut(count).ok_or(format_error(start))?;
+            *extent = Some(
+                rest[..end]
+                    .parse::<RegionSize<C>>()
+                    .map_err(|_| ParseRegionError::Size { position: start })?,
+            );
+            count += 1;
+            rest = &rest[end..];
+        }
+
+        let extents = match extents {
+            [Some(size), None, None] => [size; 3],
+            [Some(x), Some(y), Some(z)] => [x, y, z],
+            _ => return Err(format_error(body_start + body.len())),
+        };
+        Ok(Region::from_extents(position, extents).order(order))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::num::NonZero;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let region = Region::new(Coordinate::new(-1, 0, 1), NonZero::new(16).unwrap());
+        assert_eq!(region.to_string().parse(), Ok(region));
+    }
+
+    #[test]
+    fn extents() {
+        let extents = [16, 1, 300].map(|extent| NonZero::new(extent).unwrap());
+        let region = Region::with_extents(Coordinate::new(-1, 0, 1), extents);
+        assert_eq!(
+            region.to_string(),
+            "(Region: (SignedCoordinate: -1, 0, 1), 16 1 300)"
+        );
+        assert_eq!(region.to_string().parse(), Ok(region));
+    }
+
+    #[test]
+    fn empty_round_trip() {
+        assert_eq!(
+            Region::<Coordinate>::EMPTY.to_string().parse(),
+            Ok(Region::<Coordinate>::EMPTY)
+        );
+        assert_eq!(
+            "(Region: 1 2 3, 4 0 6)".parse(),
+            Ok(Region::<Coordinate>::EMPTY)
+        );
+    }
+
+    #[test]
+    fn order_round_trip() {
+        let order = IterationOrder::XZY.descending(Axis::Y);
+        let region = Region::new(Coordinate::new(-1, 0, 1), NonZero::new(2).unwrap()).order(order);
+        assert_eq!(
+            region.to_string(),
+            "(Region: (SignedCoordinate: -1, 0, 1), 2, order: x z -y)"
+        );
+        let parsed: Region = region.to_string().parse().unwrap();
+        assert_eq!(parsed, region);
+        assert!(parsed.eq(region.clone()));
+        assert_eq!(
+            region.format_with(CoordinateFormat::json()).to_string(),
+            r#"{"position":{"x":-1,"y":0,"z":1},"size":2,"order":"x z -y"}"#
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let parse = |s: &str| s.parse::<Region>().unwrap_err();
+        assert_eq!(
+            parse("Region: 1 2 3, 4"),
+            ParseRegionError::Format { position: 0 }
+        );
+        assert_eq!(
+            parse(" (Region: 1 2 3 4)"),
+            ParseRegionError::Format { position: 17 }
+        );
+        assert_eq!(
+            parse("(Region: 1 2 3, 4294967296)"),
+            ParseRegionError::Size { position: 16 }
+        );
+        assert_eq!(
+            parse("(Region: 1 2 3, 4 5)"),
+            ParseRegionError::Format { position: 19 }
+        );
+        assert_eq!(
+            parse("(Region: 1 2 3, 4 5 6 7)"),
+            ParseRegionError::Format { position: 22 }
+        );
+        assert_eq!(
+            parse("(Region: 1 2 3, 4 -1 6)"),
+            ParseRegionError::Size { position: 18 }
+        );
+        assert_eq!(
+            parse("(Region: 1 2 3, 4, order: x x y)"),
+            ParseRegionError::Order { position: 25 }
+        );
+        assert_eq!(
+            parse("(Region: 1 2 3, 4, order x y z)"),
+            ParseRegionError::Format { position: 25 }
+        );
+        assert_eq!(
+            parse("(Region: 1 a 3, 4)"),
+            ParseRegionError::Position(ParseCoordinateError::UnexpectedCharacter {
+                position: 11,
+                found: 'a'
+            })
+        );
+    }
+
+    #[test]
+    fn format_with() {
+        let region = Region::new(Coordinate::new(-1, 0, 17), NonZero::new(16).unwrap());
+        assert_eq!(
+            region.format_with(CoordinateFormat::new()).to_string(),
+            region.to_string()
+        );
+        let chunks = CoordinateFormat::compact().chunk_size(NonZero::new(16).unwrap());
+        assert_eq!(region.format_with(chunks).to_string(), "-1:15 0:0 1:1, 16");
+        let hex = CoordinateFormat::new().hex(true);
+        assert_eq!(
+            format!("{:#}", region.format_with(hex)),
+            "(Region: (SignedCoordinate: 0xffffffff, 0x0, 0x11), 0x10)"
+        );
+
+        let chunk = Region::new(
+            UnsignedCoordinate::<u8>::splat(16),
+            NonZero::new(16).unwrap(),
+        );
+        assert_eq!(
+            chunk.format_with(CoordinateFormat::new()).to_string(),
+            chunk.to_string()
+        );
+        assert_eq!(
+            chunk.format_with(CoordinateFormat::compact()).to_string(),
+            "16 16 16, 16"
+        );
+    }
+}
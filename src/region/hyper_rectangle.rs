@@ -0,0 +1,245 @@
+use num::{CheckedAdd, NumCast, One, ToPrimitive};
+
+use super::RegionField;
+use crate::prelude::*;
+
+/// Iterator of all `CoordinateN`s within an `N`-dimensional box, the first axis first.
+///
+/// Like `Region`, every axis is measured in the unsigned type of the same width as the field type,
+/// and every size is cut off at the largest representable coordinate, so iterating never overflows
+/// and yields every coordinate the `HyperRectangle` contains.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let size = [2, 1, 2].map(|size: u32| NonZero::new(size).unwrap());
+/// let hyper_rectangle = HyperRectangle::new(CoordinateN::new([0, 0, 5]), size);
+/// let positions: Vec<_> = hyper_rectangle.collect();
+/// assert_eq!(
+///     positions,
+///     [
+///         CoordinateN::new([0, 0, 5]),
+///         CoordinateN::new([1, 0, 5]),
+///         CoordinateN::new([0, 0, 6]),
+///         CoordinateN::new([1, 0, 6]),
+///     ]
+/// );
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct HyperRectangle<T, const N: usize>
+where
+    T: CoordinateField + RegionField,
+{
+    position: CoordinateN<T, N>,
+    size: [T::Size; N],
+    index: usize,
+}
+
+impl<T, const N: usize> HyperRectangle<T, N>
+where
+    T: CoordinateField + RegionField,
+{
+    /// Creates a new HyperRectangle
+    ///
+    /// - `position` corresponds to the starting position of the iterator.
+    /// - `size` detemines the range of the iterator on each axis. The range is exclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let size = [NonZero::new(3).unwrap(); 2];
+    /// let hyper_rectangle = HyperRectangle::new(CoordinateN::new([CoordinateType::MAX - 1, 0]), size);
+    /// assert_eq!(hyper_rectangle.size(), [2, 3]);
+    /// assert_eq!(hyper_rectangle.count(), 6);
+    /// ```
+    pub fn new(position: CoordinateN<T, N>, size: [T::NonZeroSize; N]) -> Self {
+        let mut size = size.map(Into::into);
+        for (size, &n) in size.iter_mut().zip(position.iter()) {
+            // Only `None` if the whole range of the field type fits, which every size does.
+            let limit = n
+                .distance(T::MAX)
+                .and_then(|distance| distance.checked_add(&T::Size::one()));
+            if let Some(limit) = limit {
+                *size = (*size).min(limit);
+            }
+        }
+        Self {
+            position,
+            size,
+            index: 0,
+        }
+    }
+
+    /// Returns the `HyperRectangle`'s starting position.
+    pub fn position(&self) -> &CoordinateN<T, N> {
+        &self.position
+    }
+
+    /// Returns the `HyperRectangle`'s size on each axis.
+    pub fn size(&self) -> [T::Size; N] {
+        self.size
+    }
+
+    /// Returns the amount of `CoordinateN`s contained in the `HyperRectangle`.
+    ///
+    /// Returns `None` if the amount doesn't fit into a `usize`.
+    pub fn volume(&self) -> Option<usize> {
+        self.size
+            .iter()
+            .try_fold(1usize, |volume, size| volume.checked_mul(size.to_usize()?))
+    }
+
+    /// Returns `true` if `coordinate` lies within the `HyperRectangle`.
+    pub fn contains(&self, coordinate: &CoordinateN<T, N>) -> bool {
+        (0..N).all(|axis| self.offset_of(coordinate, axis).is_some())
+    }
+
+    /// Returns the position of `coordinate` in the `HyperRectangle`'s iteration order.
+    ///
+    /// Returns `None` if `coordinate` lies outside of the `HyperRectangle` or the index doesn't
+    /// fit into a `usize`.
+    pub fn index_of(&self, coordinate: &CoordinateN<T, N>) -> Option<usize> {
+        (0..N).rev().try_fold(0usize, |index, axis| {
+            let offset = self.offset_of(coordinate, axis)?.to_usize()?;
+            index
+                .checked_mul(self.size[axis].to_usize()?)?
+                .checked_add(offset)
+        })
+    }
+
+    /// Returns the `CoordinateN` at `index` in the `HyperRectangle`'s iteration order.
+    ///
+    /// Returns `None` if `index` is out of bounds or the `CoordinateN` can't be represented.
+    pub fn coordinate_at(&self, mut index: usize) -> Option<CoordinateN<T, N>> {
+        let mut coordinate = self.position;
+        for (n, size) in coordinate.iter_mut().zip(&self.size) {
+            let offset = match size.to_usize() {
+                Some(size) => {
+                    let offset = index % size;
+                    index /= size;
+                    offset
+                }
+                None => core::mem::take(&mut index),
+            };
+            *n = n.checked_add_size(NumCast::from(offset)?)?;
+        }
+        (index == 0).then_some(coordinate)
+    }
+
+    /// Returns the offset of `coordinate` from `position` on `axis` if it lies within the
+    /// `HyperRectangle` on that axis.
+    fn offset_of(&self, coordinate: &CoordinateN<T, N>, axis: usize) -> Option<T::Size> {
+        self.position[axis]
+            .distance(coordinate[axis])
+            .filter(|&offset| offset < self.size[axis])
+    }
+}
+
+impl<T, const N: usize> Iterator for HyperRectangle<T, N>
+where
+    T: CoordinateField + RegionField,
+{
+    type Item = CoordinateN<T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let coordinate = self.coordinate_at(self.index)?;
+        self.index += 1;
+        Some(coordinate)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::num::NonZero;
+
+    use super::*;
+
+    fn size<const N: usize>(size: [u32; N]) -> [NonZero<u32>; N] {
+        size.map(|size| NonZero::new(size).unwrap())
+    }
+
+    #[test]
+    fn queries() {
+        let hyper_rectangle =
+            HyperRectangle::new(CoordinateN::new([-1, 4, 0, 9]), size([3, 2, 1, 4]));
+        assert_eq!(hyper_rectangle.volume(), Some(24));
+        assert!(hyper_rectangle.contains(&CoordinateN::new([1, 5, 0, 12])));
+        assert!(!hyper_rectangle.contains(&CoordinateN::new([1, 5, 1, 12])));
+        assert_eq!(
+            hyper_rectangle.index_of(&CoordinateN::new([0, 5, 0, 10])),
+            Some(10)
+        );
+        assert_eq!(
+            hyper_rectangle.coordinate_at(10),
+            Some(CoordinateN::new([0, 5, 0, 10]))
+        );
+        assert_eq!(hyper_rectangle.coordinate_at(24), None);
+
+        for (index, coordinate) in hyper_rectangle.clone().enumerate() {
+            assert_eq!(hyper_rectangle.index_of(&coordinate), Some(index));
+        }
+        assert_eq!(hyper_rectangle.count(), 24);
+    }
+
+    #[test]
+    fn iteration_near_max() {
+        let position = CoordinateN::new([CoordinateType::MAX - 1, 0, CoordinateType::MAX]);
+        let hyper_rectangle = HyperRectangle::new(position, size([3, 3, 3]));
+        assert_eq!(hyper_rectangle.size(), [2, 3, 1]);
+        assert_eq!(hyper_rectangle.volume(), Some(6));
+        assert!(hyper_rectangle.contains(&CoordinateN::new([
+            CoordinateType::MAX,
+            2,
+            CoordinateType::MAX
+        ])));
+        let coordinates: Vec<_> = hyper_rectangle.clone().collect();
+        assert_eq!(coordinates.len(), 6);
+        for coordinate in coordinates {
+            assert!(hyper_rectangle.contains(&coordinate));
+        }
+    }
+
+    #[test]
+    fn zero_dimensions() {
+        let point = HyperRectangle::new(CoordinateN::<CoordinateType, 0>::new([]), []);
+        assert_eq!(point.volume(), Some(1));
+        assert_eq!(point.count(), 1);
+    }
+
+    #[test]
+    fn huge_volume() {
+        let hyper_rectangle = HyperRectangle::new(CoordinateN::splat(0), size([255; 16]));
+        assert_eq!(hyper_rectangle.volume(), None);
+        assert_eq!(hyper_rectangle.index_of(&CoordinateN::splat(254)), None);
+        let last = hyper_rectangle.coordinate_at(usize::MAX).unwrap();
+        assert_eq!(hyper_rectangle.index_of(&last), Some(usize::MAX));
+    }
+
+    #[test]
+    fn other_field_types() {
+        let size = [NonZero::new(1_000).unwrap(), NonZero::new(2).unwrap()];
+        let hyper_rectangle = HyperRectangle::new(CoordinateN::new([u16::MAX - 1, 7]), size);
+        assert_eq!(hyper_rectangle.size(), [2, 2]);
+        assert_eq!(
+            hyper_rectangle.clone().last(),
+            Some(CoordinateN::new([u16::MAX, 8]))
+        );
+
+        let wide = HyperRectangle::new(CoordinateN::new([-500i64]), [NonZero::new(1_000).unwrap()]);
+        assert_eq!(wide.volume(), Some(1_000));
+        assert_eq!(wide.index_of(&CoordinateN::new([499])), Some(999));
+        assert_eq!(wide.last(), Some(CoordinateN::new([499])));
+
+        let full = HyperRectangle::new(
+            CoordinateN::new([i8::MIN]),
+            [NonZero::new(u8::MAX).unwrap()],
+        );
+        assert_eq!(full.count(), 255);
+    }
+}
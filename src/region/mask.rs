@@ -0,0 +1,605 @@
+use alloc::{borrow::ToOwned, collections::BTreeSet, vec, vec::Vec};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+
+use thiserror::Error;
+
+use super::RegionField;
+use crate::flood_fill::{connected_components, Components};
+use crate::prelude::*;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone, Error)]
+#[error("{0} lies outside of the mask's region.")]
+pub struct OutsideRegionError<C = Coordinate>(pub C);
+
+/// Ordered set of coordinates without any bounds, which a `RegionMask` converts from and into.
+pub type CoordinateSet<C = Coordinate> = BTreeSet<C>;
+
+/// Set of coordinates within a `Region`, stored as one bit per coordinate.
+///
+/// Union and symmetric difference (`|` and `^`) produce a mask covering the bounding box of both
+/// masks' regions, iterated in the left-hand side's order. Intersection and difference (`&` and `-`)
+/// can't grow the set, so they keep the left-hand side's `Region`.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// #     test().unwrap();
+/// # }
+/// # fn test() -> Option<()> {
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let region = Region::new(Coordinate::splat(0), NonZero::new(4)?);
+/// let mut mask = RegionMask::new(region);
+/// mask.insert(Coordinate::new(1, 2, 3)).ok()?;
+/// mask.insert(Coordinate::new(0, 0, 0)).ok()?;
+///
+/// assert!(mask.contains(&Coordinate::new(1, 2, 3)));
+/// assert_eq!(mask.count(), 2);
+/// assert_eq!(mask.iter().next(), Some(Coordinate::new(0, 0, 0)));
+/// # Some(())
+/// # }
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct RegionMask<C = Coordinate>
+where
+    C: CoordinateTrait,
+    C::FieldType: RegionField,
+{
+    region: Region<C>,
+    volume: usize,
+    bits: Vec<u64>,
+}
+
+impl<C> RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    /// Creates a new `RegionMask` without any coordinates set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the volume of `region` doesn't fit into a `usize`.
+    pub fn new(region: Region<C>) -> Self {
+        let volume = region
+            .volume_usize()
+            .expect("the region is too large for a `RegionMask`");
+        Self {
+            region: region.rewound(),
+            volume,
+            bits: vec![0; volume.div_ceil(WORD_BITS)],
+        }
+    }
+
+    /// Creates a new `RegionMask` with every coordinate of the `Region` set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the volume of `region` doesn't fit into a `usize`.
+    pub fn full(region: Region<C>) -> Self {
+        let mut mask = Self::new(region);
+        mask.bits.fill(u64::MAX);
+        mask.clear_padding();
+        mask
+    }
+
+    /// Creates a new `RegionMask` with the given coordinates set.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first coordinate that lies outside of `region`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the volume of `region` doesn't fit into a `usize`.
+    pub fn from_coordinates<I>(
+        region: Region<C>,
+        coordinates: I,
+    ) -> Result<Self, OutsideRegionError<C>>
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let mut mask = Self::new(region);
+        for coordinate in coordinates {
+            mask.insert(coordinate)?;
+        }
+        Ok(mask)
+    }
+
+    /// Returns the `Region` covered by the mask.
+    pub fn region(&self) -> &Region<C> {
+        &self.region
+    }
+
+    /// Returns `true` if `coordinate` is set.
+    pub fn contains(&self, coordinate: &C) -> bool {
+        self.region
+            .index_of(coordinate)
+            .is_some_and(|index| self.get(index))
+    }
+
+    /// Sets `coordinate`, returning `true` if it wasn't set before.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `coordinate` lies outside of the mask's `Region`.
+    pub fn insert(&mut self, coordinate: C) -> Result<bool, OutsideRegionError<C>> {
+        match self.region.index_of(&coordinate) {
+            Some(index) => {
+                let was_set = self.get(index);
+                self.set(index, true);
+                Ok(!was_set)
+            }
+            None => Err(OutsideRegionError(coordinate)),
+        }
+    }
+
+    /// Unsets `coordinate`, returning `true` if it was set before.
+    pub fn remove(&mut self, coordinate: &C) -> bool {
+        match self.region.index_of(coordinate) {
+            Some(index) => {
+                let was_set = self.get(index);
+                self.set(index, false);
+                was_set
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the amount of set coordinates.
+    pub fn count(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if no coordinate is set.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// Unsets every coordinate.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    /// Iterates over all set coordinates in the `Region`'s iteration order.
+    pub fn iter(&self) -> MaskIter<'_, C> {
+        MaskIter {
+            mask: self,
+            word: 0,
+            bits: self.bits.first().copied().unwrap_or_default(),
+        }
+    }
+
+    fn get(&self, index: usize) -> bool {
+        self.bits[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+    }
+
+    fn set(&mut self, index: usize, value: bool) {
+        let word = &mut self.bits[index / WORD_BITS];
+        match value {
+            true => *word |= 1 << (index % WORD_BITS),
+            false => *word &= !(1 << (index % WORD_BITS)),
+        }
+    }
+
+    /// Unused bits of the last word must stay unset, otherwise `count` and `==` break.
+    fn clear_padding(&mut self) {
+        let used = self.volume % WORD_BITS;
+        if let (Some(last), true) = (self.bits.last_mut(), used != 0) {
+            *last &= (1 << used) - 1;
+        }
+    }
+
+    fn same_bounds(&self, other: &Self) -> bool {
+        same_region(&self.region, &other.region)
+    }
+
+    /// Grows the mask to the bounding box of both masks' regions, keeping its set coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounding box is too large for a `RegionMask`.
+    fn widen(&mut self, other: &Self) {
+        let corners = [&self.region, &other.region]
+            .into_iter()
+            .filter_map(Region::corners)
+            .flat_map(|(min, max)| [min, max]);
+        let region = bounding_region(corners).order(self.region.order);
+        if !same_region(&region, &self.region) {
+            let mut mask = Self::new(region);
+            mask.set_all(self.iter());
+            *self = mask;
+        }
+    }
+
+    /// Sets all `coordinates`, which must lie within the mask's `Region`.
+    fn set_all(&mut self, coordinates: impl IntoIterator<Item = C>) {
+        for coordinate in coordinates {
+            let index = self
+                .region
+                .index_of(&coordinate)
+                .expect("the coordinate lies within the bounding box");
+            self.set(index, true);
+        }
+    }
+
+    fn combine(&mut self, other: &Self, words: fn(u64, u64) -> u64, bits: fn(bool, bool) -> bool) {
+        if self.same_bounds(other) {
+            for (word, &other_word) in self.bits.iter_mut().zip(&other.bits) {
+                *word = words(*word, other_word);
+            }
+            return;
+        }
+
+        for index in 0..self.volume {
+            let other_bit = self
+                .region
+                .coordinate_at(index)
+                .is_some_and(|coordinate| other.contains(&coordinate));
+            self.set(index, bits(self.get(index), other_bit));
+        }
+    }
+}
+
+impl RegionMask {
+    /// Labels the set `Coordinate`s by their connected component.
+    pub fn connected_components(&self, connectivity: Connectivity) -> Components {
+        connected_components(&self.region, connectivity, |coordinate| {
+            self.contains(coordinate)
+        })
+    }
+}
+
+/// Returns `true` if both regions cover the same coordinates in the same order.
+fn same_region<C>(a: &Region<C>, b: &Region<C>) -> bool
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    let position = |region: &Region<C>| -> [C::FieldType; 3] { region.position.into() };
+    position(a) == position(b) && a.extents == b.extents && a.order == b.order
+}
+
+/// Returns the smallest `Region` containing all `coordinates`, which is empty if there are none.
+///
+/// # Panics
+///
+/// Panics if the extents of the `Region` don't fit into its size type.
+fn bounding_region<C>(coordinates: impl IntoIterator<Item = C>) -> Region<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    let mut fields = coordinates.into_iter().map(Into::<[C::FieldType; 3]>::into);
+    let Some(first) = fields.next() else {
+        return Region::EMPTY;
+    };
+    let ([min_x, min_y, min_z], [max_x, max_y, max_z]) =
+        fields.fold((first, first), |(min, max), fields| {
+            (
+                core::array::from_fn(|axis| min[axis].min(fields[axis])),
+                core::array::from_fn(|axis| max[axis].max(fields[axis])),
+            )
+        });
+    Region::from_corners(C::new(min_x, min_y, min_z), C::new(max_x, max_y, max_z))
+        .expect("the bounding box is too large for a `Region`")
+}
+
+impl<C> From<&RegionMask<C>> for CoordinateSet<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Ord + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    fn from(mask: &RegionMask<C>) -> Self {
+        mask.iter().collect()
+    }
+}
+
+impl<C> From<&CoordinateSet<C>> for RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Ord + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    /// Creates a mask over the bounding box of `set`, with all of its coordinates set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounding box is too large for a `RegionMask`.
+    fn from(set: &CoordinateSet<C>) -> Self {
+        let mut mask = Self::new(bounding_region(set.iter().copied()));
+        mask.set_all(set.iter().copied());
+        mask
+    }
+}
+
+impl<'a, C> IntoIterator for &'a RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    type Item = C;
+    type IntoIter = MaskIter<'a, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the set coordinates of a `RegionMask`.
+///
+/// Created by [`RegionMask::iter`].
+#[derive(Debug, Clone)]
+pub struct MaskIter<'a, C = Coordinate>
+where
+    C: CoordinateTrait,
+    C::FieldType: RegionField,
+{
+    mask: &'a RegionMask<C>,
+    word: usize,
+    bits: u64,
+}
+
+impl<C> Iterator for MaskIter<'_, C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.bits == 0 {
+                self.word += 1;
+                self.bits = *self.mask.bits.get(self.word)?;
+            }
+
+            let bit = self.bits.trailing_zeros() as usize;
+            self.bits &= self.bits - 1;
+            if let Some(coordinate) = self.mask.region.coordinate_at(self.word * WORD_BITS + bit) {
+                return Some(coordinate);
+            }
+        }
+    }
+}
+
+impl<C> BitAndAssign<&RegionMask<C>> for RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    fn bitand_assign(&mut self, rhs: &RegionMask<C>) {
+        self.combine(rhs, |a, b| a & b, |a, b| a && b);
+    }
+}
+
+impl<C> BitOrAssign<&RegionMask<C>> for RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    fn bitor_assign(&mut self, rhs: &RegionMask<C>) {
+        self.widen(rhs);
+        self.combine(rhs, |a, b| a | b, |a, b| a || b);
+    }
+}
+
+impl<C> BitXorAssign<&RegionMask<C>> for RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    fn bitxor_assign(&mut self, rhs: &RegionMask<C>) {
+        self.widen(rhs);
+        self.combine(rhs, |a, b| a ^ b, |a, b| a ^ b);
+    }
+}
+
+impl<C> SubAssign<&RegionMask<C>> for RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    fn sub_assign(&mut self, rhs: &RegionMask<C>) {
+        self.combine(rhs, |a, b| a & !b, |a, b| a && !b);
+    }
+}
+
+impl<C> BitAnd for &RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    type Output = RegionMask<C>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let mut result = self.to_owned();
+        result &= rhs;
+        result
+    }
+}
+
+impl<C> BitOr for &RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    type Output = RegionMask<C>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let mut result = self.to_owned();
+        result |= rhs;
+        result
+    }
+}
+
+impl<C> BitXor for &RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    type Output = RegionMask<C>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        let mut result = self.to_owned();
+        result ^= rhs;
+        result
+    }
+}
+
+impl<C> Sub for &RegionMask<C>
+where
+    C: CoordinateTrait<Type = C> + Copy + Into<[C::FieldType; 3]>,
+    C::FieldType: RegionField,
+{
+    type Output = RegionMask<C>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = self.to_owned();
+        result -= rhs;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::tests::region;
+
+    #[test]
+    fn insert_and_remove() {
+        let mut mask = RegionMask::new(region(Coordinate::splat(-2), 4));
+        assert!(mask.insert(Coordinate::new(-1, 0, 1)).unwrap());
+        assert!(!mask.insert(Coordinate::new(-1, 0, 1)).unwrap());
+        assert!(mask.insert(Coordinate::splat(2)).is_err());
+        assert_eq!(mask.count(), 1);
+
+        assert!(mask.remove(&Coordinate::new(-1, 0, 1)));
+        assert!(!mask.remove(&Coordinate::new(-1, 0, 1)));
+        assert!(mask.is_empty());
+    }
+
+    #[test]
+    fn full() {
+        let mask = RegionMask::full(region(Coordinate::splat(0), 5));
+        assert_eq!(mask.count(), 125);
+        assert!(mask.iter().eq(region(Coordinate::splat(0), 5)));
+    }
+
+    #[test]
+    fn iterates_in_region_order() {
+        let coordinates = [
+            Coordinate::new(3, 3, 3),
+            Coordinate::new(1, 0, 0),
+            Coordinate::new(0, 1, 0),
+        ];
+        let mask =
+            RegionMask::from_coordinates(region(Coordinate::splat(0), 4), coordinates).unwrap();
+        let expected = vec![
+            Coordinate::new(1, 0, 0),
+            Coordinate::new(0, 1, 0),
+            Coordinate::new(3, 3, 3),
+        ];
+        assert_eq!(mask.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn operations_same_region() {
+        let a = RegionMask::from_coordinates(
+            region(Coordinate::splat(0), 3),
+            [Coordinate::splat(0), Coordinate::splat(1)],
+        )
+        .unwrap();
+        let b = RegionMask::from_coordinates(
+            region(Coordinate::splat(0), 3),
+            [Coordinate::splat(1), Coordinate::splat(2)],
+        )
+        .unwrap();
+
+        assert_eq!((&a | &b).count(), 3);
+        assert_eq!(
+            (&a & &b).iter().collect::<Vec<_>>(),
+            vec![Coordinate::splat(1)]
+        );
+        assert_eq!(
+            (&a ^ &b).iter().collect::<Vec<_>>(),
+            vec![Coordinate::splat(0), Coordinate::splat(2)]
+        );
+        assert_eq!(
+            (&a - &b).iter().collect::<Vec<_>>(),
+            vec![Coordinate::splat(0)]
+        );
+    }
+
+    #[test]
+    fn operations_different_regions() {
+        let a = RegionMask::full(region(Coordinate::splat(0), 2));
+        let b = RegionMask::from_coordinates(
+            region(Coordinate::splat(1), 2),
+            [Coordinate::splat(1), Coordinate::splat(2)],
+        )
+        .unwrap();
+
+        let union = &a | &b;
+        assert_eq!(union.region(), &region(Coordinate::splat(0), 3));
+        assert_eq!(union.count(), 9);
+        assert!(union.contains(&Coordinate::splat(2)));
+        assert_eq!(
+            (&b ^ &a).iter().collect::<Vec<_>>(),
+            RegionMask::full(region(Coordinate::splat(0), 2))
+                .iter()
+                .filter(|&coordinate| coordinate != Coordinate::splat(1))
+                .chain([Coordinate::splat(2)])
+                .collect::<Vec<_>>()
+        );
+        assert_eq!((&b ^ &a).region(), union.region());
+        assert_eq!(
+            (&a & &b).iter().collect::<Vec<_>>(),
+            vec![Coordinate::splat(1)]
+        );
+        assert_eq!((&a - &b).count(), 7);
+        assert_eq!(
+            (&b - &a).iter().collect::<Vec<_>>(),
+            vec![Coordinate::splat(2)]
+        );
+    }
+
+    #[test]
+    fn union_keeps_left_order() {
+        let a = RegionMask::full(region(Coordinate::splat(0), 1).order(IterationOrder::ZYX));
+        let b = RegionMask::full(region(Coordinate::new(1, 0, 0), 1));
+        let union = &a | &b;
+        assert_eq!(union.region().iteration_order(), IterationOrder::ZYX);
+        assert_eq!(union.count(), 2);
+
+        let mut c = RegionMask::new(Region::<Coordinate>::EMPTY);
+        c |= &b;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn coordinate_set() {
+        let set = CoordinateSet::from([
+            Coordinate::new(-1, 4, 0),
+            Coordinate::new(2, 0, 0),
+            Coordinate::new(0, 0, 3),
+        ]);
+        let mask = RegionMask::from(&set);
+        assert_eq!(
+            mask.region(),
+            &Region::from_corners(Coordinate::new(-1, 0, 0), Coordinate::new(2, 4, 3)).unwrap()
+        );
+        assert_eq!(mask.count(), 3);
+        assert_eq!(CoordinateSet::from(&mask), set);
+
+        let empty = RegionMask::<Coordinate>::from(&CoordinateSet::new());
+        assert!(empty.region().is_empty());
+        assert_eq!(CoordinateSet::from(&empty), CoordinateSet::new());
+    }
+}
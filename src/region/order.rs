@@ -0,0 +1,142 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::prelude::*;
+
+/// Order in which a `Region` visits its coordinates, which also determines its indices.
+///
+/// Lists the axes from the fastest to the slowest changing one. Every axis is traversed
+/// ascending unless it is marked as [`descending`](IterationOrder::descending).
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// #     test().unwrap();
+/// # }
+/// # fn test() -> Option<()> {
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// // y-major chunk storage, from the top layer down.
+/// let order = IterationOrder::XZY.descending(Axis::Y);
+/// let region = Region::new(Coordinate::splat(0), NonZero::new(2)?).order(order);
+/// assert_eq!(region.index_of(&Coordinate::new(1, 1, 0)), Some(1));
+/// assert_eq!(region.index_of(&Coordinate::new(0, 1, 1)), Some(2));
+/// assert_eq!(region.index_of(&Coordinate::new(0, 0, 0)), Some(4));
+///
+/// let positions: Vec<_> = region.take(3).collect();
+/// assert_eq!(
+///     positions,
+///     [
+///         Coordinate::new(0, 1, 0),
+///         Coordinate::new(1, 1, 0),
+///         Coordinate::new(0, 1, 1),
+///     ]
+/// );
+/// # Some(())
+/// # }
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct IterationOrder {
+    axes: [Axis; 3],
+    descending: [bool; 3],
+}
+
+impl IterationOrder {
+    /// `x` first, then `y`, then `z`, the default order.
+    pub const XYZ: Self = Self::ascending([Axis::X, Axis::Y, Axis::Z]);
+    /// `x` first, then `z`, then `y`.
+    pub const XZY: Self = Self::ascending([Axis::X, Axis::Z, Axis::Y]);
+    /// `y` first, then `x`, then `z`.
+    pub const YXZ: Self = Self::ascending([Axis::Y, Axis::X, Axis::Z]);
+    /// `y` first, then `z`, then `x`.
+    pub const YZX: Self = Self::ascending([Axis::Y, Axis::Z, Axis::X]);
+    /// `z` first, then `x`, then `y`.
+    pub const ZXY: Self = Self::ascending([Axis::Z, Axis::X, Axis::Y]);
+    /// `z` first, then `y`, then `x`.
+    pub const ZYX: Self = Self::ascending([Axis::Z, Axis::Y, Axis::X]);
+
+    /// Creates an ascending order of `axes`, from the fastest to the slowest changing one.
+    ///
+    /// Returns `None` if `axes` isn't a permutation of all three axes.
+    pub fn new(axes: [Axis; 3]) -> Option<Self> {
+        let [a, b, c] = axes;
+        (a != b && b != c && a != c).then_some(Self::ascending(axes))
+    }
+
+    const fn ascending(axes: [Axis; 3]) -> Self {
+        Self {
+            axes,
+            descending: [false; 3],
+        }
+    }
+
+    /// Traverses `axis` from the largest to the smallest coordinate.
+    pub fn descending(mut self, axis: Axis) -> Self {
+        self.descending[axis.index()] = true;
+        self
+    }
+
+    /// Returns the axes from the fastest to the slowest changing one.
+    pub fn axes(&self) -> [Axis; 3] {
+        self.axes
+    }
+
+    /// Returns `true` if `axis` is traversed from the largest to the smallest coordinate.
+    pub fn is_descending(&self, axis: Axis) -> bool {
+        self.descending[axis.index()]
+    }
+}
+
+impl Default for IterationOrder {
+    fn default() -> Self {
+        Self::XYZ
+    }
+}
+
+/// Formats the axes from the fastest to the slowest changing one, prefixing descending axes with
+/// `-`, e.g. `x z -y`.
+impl Display for IterationOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, axis) in self.axes.into_iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            if self.is_descending(axis) {
+                f.write_str("-")?;
+            }
+            write!(f, "{axis}")?;
+        }
+        Ok(())
+    }
+}
+
+impl IterationOrder {
+    /// Parses the `Display` format, returning `None` if `s` doesn't match it.
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let mut axes = [Axis::X; 3];
+        let mut descending = [false; 3];
+        let mut tokens = s.split_whitespace();
+        for axis in &mut axes {
+            let token = tokens.next()?;
+            let (is_descending, token) = match token.strip_prefix('-') {
+                Some(token) => (true, token),
+                None => (false, token),
+            };
+            *axis = match token {
+                "x" => Axis::X,
+                "y" => Axis::Y,
+                "z" => Axis::Z,
+                _ => return None,
+            };
+            descending[axis.index()] = is_descending;
+        }
+        if tokens.next().is_some() {
+            return None;
+        }
+        Some(Self {
+            descending,
+            ..Self::new(axes)?
+        })
+    }
+}
@@ -0,0 +1,361 @@
+use core::fmt::{self, Debug, Formatter};
+
+use num::{One, Zero};
+
+use super::{IterationOrder, NonZeroRegionSize, Region, RegionError, RegionField, RegionSize};
+use crate::prelude::*;
+
+/// Rectangle-shaped iterator of 2D coordinates, `Coordinate2`s by default. The 2D counterpart of
+/// `Region`.
+///
+/// The iterator returns all coordinates from `position` to `position + extents - 1`, `x` first.
+/// Like `Region`, every axis may have its own extent, the iteration order is configurable, and
+/// every extent is cut off at the largest representable coordinate.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let rectangle = Rectangle::new(Coordinate2::splat(7), NonZero::new(2).unwrap());
+/// let positions: Vec<_> = rectangle.collect();
+/// assert_eq!(
+///     positions,
+///     [
+///         Coordinate2::new(7, 7),
+///         Coordinate2::new(8, 7),
+///         Coordinate2::new(7, 8),
+///         Coordinate2::new(8, 8),
+///     ]
+/// );
+///
+/// // A heightmap tile of 16 by 4 columns.
+/// let extents = [NonZero::<u8>::new(16).unwrap(), NonZero::new(4).unwrap()];
+/// let tile = Rectangle::with_extents(UnsignedCoordinate2::<u8>::splat(0), extents);
+/// assert_eq!(tile.area(), Some(64));
+/// ```
+#[derive(PartialEq, Clone)]
+pub struct Rectangle<C = Coordinate2>
+where
+    C: Coordinate2Trait,
+    C::FieldType: RegionField,
+{
+    position: C,
+    /// The rectangle as a `Region` one coordinate deep, at the smallest `z`.
+    region: Region<C::Coordinate3>,
+}
+
+impl<C> Rectangle<C>
+where
+    C: Coordinate2Trait<Type = C> + Copy + Into<[C::FieldType; 2]>,
+    C::FieldType: RegionField,
+{
+    /// The empty `Rectangle`, which contains no coordinates.
+    ///
+    /// Every empty `Rectangle` is equal to this one.
+    pub const EMPTY: Self = Self {
+        position: C::MIN,
+        region: Region::EMPTY,
+    };
+
+    /// Creates a new square-shaped Rectangle
+    ///
+    /// - `position` corresponds to the starting position of the iterator.
+    /// - `size` detemines the range of the iterator on both axes. The range is exclusive.
+    ///
+    /// The `Rectangle` is cut off at the largest representable coordinate, so iterating it never
+    /// overflows. Use [`Rectangle::try_new`] to reject such rectangles instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let position = Coordinate2::new(CoordinateType::MAX - 1, 0);
+    /// let rectangle = Rectangle::new(position, NonZero::new(3).unwrap());
+    /// assert_eq!(rectangle.extents(), [2, 3]);
+    /// assert_eq!(rectangle.last(), Some(Coordinate2::new(CoordinateType::MAX, 2)));
+    /// ```
+    pub fn new(position: C, size: NonZeroRegionSize<C>) -> Self {
+        Self::with_extents(position, [size; 2])
+    }
+
+    /// Creates a new Rectangle with a separate size for both axes.
+    ///
+    /// Like [`Rectangle::new`], every extent is cut off at the largest representable coordinate.
+    pub fn with_extents(position: C, extents: [NonZeroRegionSize<C>; 2]) -> Self {
+        Self::from_extents(position, extents.map(Into::into))
+    }
+
+    /// Creates a new square-shaped Rectangle, which is empty if `size` is `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegionError::Overflow`] if the `Rectangle` would extend past the largest
+    /// representable coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use argentum_game_coordinate_system::prelude::*;
+    /// use argentum_game_coordinate_system::region::RegionError;
+    ///
+    /// let rectangle = Rectangle::try_new(Coordinate2::splat(0), 0).unwrap();
+    /// assert!(rectangle.is_empty());
+    ///
+    /// let rectangle = Rectangle::try_new(Coordinate2::new(0, CoordinateType::MAX - 1), 3);
+    /// assert_eq!(rectangle, Err(RegionError::Overflow { axis: Axis::Y }));
+    /// ```
+    pub fn try_new(position: C, size: RegionSize<C>) -> Result<Self, RegionError> {
+        if size.is_zero() {
+            return Ok(Self::EMPTY);
+        }
+
+        let fields: [C::FieldType; 2] = position.into();
+        for (axis, field) in Axis::ALL.into_iter().zip(fields) {
+            field
+                .checked_add_size(size - RegionSize::<C>::one())
+                .ok_or(RegionError::Overflow { axis })?;
+        }
+        Ok(Self::from_extents(position, [size; 2]))
+    }
+
+    /// Creates the Rectangle spanning from `min` to `max`, both inclusive.
+    ///
+    /// The `Rectangle` is empty if `max` is smaller than `min` on either axis.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegionError::TooLarge`] if an extent doesn't fit into the size type, which can
+    /// only happen if the corners lie on opposite limits of the field type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let rectangle = Rectangle::from_corners(Coordinate2::new(0, 0), Coordinate2::new(2, 1));
+    /// assert_eq!(rectangle.map(|rectangle| rectangle.extents()), Ok([3, 2]));
+    ///
+    /// let rectangle = Rectangle::from_corners(Coordinate2::new(0, 0), Coordinate2::new(2, -1));
+    /// assert_eq!(rectangle, Ok(Rectangle::EMPTY));
+    /// ```
+    pub fn from_corners(min: C, max: C) -> Result<Self, RegionError> {
+        Region::from_corners(Self::lift(min), Self::lift(max)).map(Self::from_region)
+    }
+
+    /// Creates a new Rectangle, cutting off every extent at the largest representable coordinate.
+    pub(crate) fn from_extents(position: C, extents: [RegionSize<C>; 2]) -> Self {
+        let [x, y] = extents;
+        let region = Region::from_extents(Self::lift(position), [x, y, RegionSize::<C>::one()]);
+        Self::from_region(region)
+    }
+
+    /// Wraps a `Region` one coordinate deep at the smallest `z`.
+    fn from_region(region: Region<C::Coordinate3>) -> Self {
+        Self {
+            position: Self::project(*region.position()),
+            region,
+        }
+    }
+
+    /// Sets the order the `Rectangle` is iterated in, which [`Rectangle::index_of`] and
+    /// [`Rectangle::coordinate_at`] follow as well.
+    ///
+    /// Only the order of `x` and `y` matters, the position of [`Axis::Z`] is ignored. Restarts
+    /// the iteration. Empty rectangles are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZero;
+    /// use argentum_game_coordinate_system::prelude::*;
+    ///
+    /// let order = IterationOrder::YXZ.descending(Axis::Y);
+    /// let rectangle = Rectangle::new(Coordinate2::splat(0), NonZero::new(2).unwrap()).order(order);
+    /// assert_eq!(rectangle.coordinate_at(1), Some(Coordinate2::new(0, 0)));
+    /// assert_eq!(rectangle.last(), Some(Coordinate2::new(1, 0)));
+    /// ```
+    pub fn order(self, order: IterationOrder) -> Self {
+        Self::from_region(self.region.order(order))
+    }
+
+    /// Returns the order the `Rectangle` is iterated in.
+    pub fn iteration_order(&self) -> IterationOrder {
+        self.region.iteration_order()
+    }
+
+    /// Returns the `Rectangle`'s starting position.
+    pub fn position(&self) -> &C {
+        &self.position
+    }
+
+    /// Returns the `Rectangle`'s size, or `None` if its extents differ.
+    pub fn size(&self) -> Option<RegionSize<C>> {
+        let [x, y] = self.extents();
+        (x == y).then_some(x)
+    }
+
+    /// Returns the `Rectangle`'s size on both axes.
+    pub fn extents(&self) -> [RegionSize<C>; 2] {
+        let [x, y, _] = self.region.extents();
+        [x, y]
+    }
+
+    /// Returns the amount of coordinates contained in the `Rectangle`.
+    ///
+    /// Only returns `None` for 64 bit or wider field types, if the amount doesn't fit into a `u128`.
+    pub fn area(&self) -> Option<u128> {
+        self.region.volume()
+    }
+
+    /// Returns the amount of coordinates the iterator has yet to yield.
+    ///
+    /// Returns `None` if the amount doesn't fit into a `usize`.
+    pub fn len(&self) -> Option<usize> {
+        self.region.len()
+    }
+
+    /// Returns `true` if the `Rectangle` contains no coordinates.
+    pub fn is_empty(&self) -> bool {
+        self.region.is_empty()
+    }
+
+    /// Returns `true` if `coordinate` lies within the `Rectangle`.
+    pub fn contains(&self, coordinate: &C) -> bool {
+        self.region.corners().is_some_and(|(min, max)| {
+            coordinate.cmpge(Self::project(min)).all() && coordinate.cmple(Self::project(max)).all()
+        })
+    }
+
+    /// Returns the position of `coordinate` in the `Rectangle`'s iteration order.
+    ///
+    /// Returns `None` if `coordinate` lies outside of the `Rectangle` or the position doesn't fit
+    /// into a `usize`.
+    pub fn index_of(&self, coordinate: &C) -> Option<usize> {
+        self.region.index_of(&Self::lift(*coordinate))
+    }
+
+    /// Returns the coordinate at `index` in the `Rectangle`'s iteration order.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn coordinate_at(&self, index: usize) -> Option<C> {
+        self.region.coordinate_at(index).map(Self::project)
+    }
+
+    /// Turns `coordinate` into the 3D coordinate at the smallest `z`.
+    fn lift(coordinate: C) -> C::Coordinate3 {
+        let [x, y]: [C::FieldType; 2] = coordinate.into();
+        let [_, _, z]: [C::FieldType; 3] = C::Coordinate3::MIN.into();
+        C::Coordinate3::new(x, y, z)
+    }
+
+    /// Drops the `z` axis of `coordinate`.
+    fn project(coordinate: C::Coordinate3) -> C {
+        let [x, y, _]: [C::FieldType; 3] = coordinate.into();
+        C::new(x, y)
+    }
+}
+
+impl<C> Debug for Rectangle<C>
+where
+    C: Coordinate2Trait + Debug,
+    C::Coordinate3: Debug,
+    C::FieldType: RegionField,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rectangle")
+            .field("position", &self.position)
+            .field("region", &self.region)
+            .finish()
+    }
+}
+
+impl<C> Iterator for Rectangle<C>
+where
+    C: Coordinate2Trait<Type = C> + Copy + Into<[C::FieldType; 2]>,
+    C::FieldType: RegionField,
+{
+    type Item = C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.region.next().map(Self::project)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.region.size_hint()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::num::NonZero;
+
+    use super::*;
+
+    #[test]
+    fn queries() {
+        let rectangle = Rectangle::new(Coordinate2::new(-1, 4), NonZero::new(3).unwrap());
+        assert_eq!(rectangle.area(), Some(9));
+        assert_eq!(rectangle.len(), Some(9));
+        assert!(rectangle.contains(&Coordinate2::new(1, 6)));
+        assert!(!rectangle.contains(&Coordinate2::new(2, 6)));
+        assert_eq!(rectangle.index_of(&Coordinate2::new(0, 5)), Some(4));
+        assert_eq!(rectangle.coordinate_at(4), Some(Coordinate2::new(0, 5)));
+        assert_eq!(rectangle.coordinate_at(9), None);
+
+        for (index, coordinate) in rectangle.clone().enumerate() {
+            assert_eq!(rectangle.index_of(&coordinate), Some(index));
+        }
+        assert_eq!(rectangle.count(), 9);
+    }
+
+    #[test]
+    fn extents_and_order() {
+        let extents = [NonZero::new(3).unwrap(), NonZero::new(2).unwrap()];
+        let order = IterationOrder::YXZ.descending(Axis::X);
+        let rectangle =
+            Rectangle::with_extents(UnsignedCoordinate2::<u8>::new(4, 9), extents).order(order);
+        assert_eq!(rectangle.size(), None);
+        assert_eq!(rectangle.iteration_order(), order);
+        assert_eq!(
+            rectangle.coordinate_at(1),
+            Some(UnsignedCoordinate2::new(6, 10))
+        );
+        for (index, coordinate) in rectangle.clone().enumerate() {
+            assert_eq!(rectangle.index_of(&coordinate), Some(index));
+        }
+        assert_eq!(rectangle.count(), 6);
+    }
+
+    #[test]
+    fn iteration_near_max() {
+        let position = Coordinate2::new(CoordinateType::MAX - 1, 1);
+        let rectangle = Rectangle::new(position, NonZero::new(3).unwrap());
+        assert_eq!(rectangle.extents(), [2, 3]);
+        assert_eq!(rectangle.area(), Some(6));
+        assert!(rectangle.contains(&Coordinate2::new(CoordinateType::MAX - 1, 1)));
+        let coordinates: Vec<_> = rectangle.clone().collect();
+        assert_eq!(coordinates.len(), 6);
+        for coordinate in coordinates {
+            assert!(rectangle.contains(&coordinate));
+        }
+        assert_eq!(
+            Rectangle::try_new(position, 3),
+            Err(RegionError::Overflow { axis: Axis::X })
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let rectangle = Rectangle::from_corners(Coordinate2::new(3, 0), Coordinate2::new(2, 5));
+        assert_eq!(rectangle, Ok(Rectangle::EMPTY));
+        let rectangle = Rectangle::<Coordinate2>::EMPTY;
+        assert!(rectangle.is_empty());
+        assert_eq!(rectangle.position(), &Coordinate2::MIN);
+        assert_eq!(rectangle.area(), Some(0));
+        assert_eq!(rectangle.count(), 0);
+    }
+}
@@ -0,0 +1,318 @@
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    num::NonZero,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+use crate::prelude::*;
+
+/// Marker for the space a [`SpaceCoordinate`] lives in.
+pub trait Space {}
+
+/// Absolute block positions.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct World;
+
+/// Positions of whole chunks, counted in chunks from the origin.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Chunk;
+
+/// Positions relative to the origin of the chunk containing them.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Local;
+
+impl Space for World {}
+impl Space for Chunk {}
+impl Space for Local {}
+
+/// A coordinate tagged with the [`Space`] it lives in.
+///
+/// Arithmetic is only implemented between coordinates of the same space, so mixing up spaces
+/// fails to compile. Converting between spaces goes through explicit functions.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// #     test().unwrap();
+/// # }
+/// # fn test() -> Option<()> {
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let chunk_size = NonZero::new(16)?;
+/// let world = WorldCoordinate::new(Coordinate::new(-1, 20, 33));
+/// let (chunk, local) = world.split(chunk_size);
+///
+/// assert_eq!(chunk.as_inner(), &Coordinate::new(-1, 1, 2));
+/// assert_eq!(local.as_inner(), &Coordinate::new(15, 4, 1));
+/// assert_eq!(chunk.to_world(&local, chunk_size)?, world);
+/// # Some(())
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// use argentum_game_coordinate_system::prelude::*;
+///
+/// let world = WorldCoordinate::new(Coordinate::new(1, 2, 3));
+/// let chunk = ChunkCoordinate::new(Coordinate::new(1, 2, 3));
+/// let _ = world + chunk;
+/// ```
+#[derive(Debug)]
+pub struct SpaceCoordinate<S: Space, C = Coordinate> {
+    coordinate: C,
+    space: PhantomData<S>,
+}
+
+/// Absolute block position.
+pub type WorldCoordinate = SpaceCoordinate<World>;
+
+/// Position of a chunk.
+pub type ChunkCoordinate = SpaceCoordinate<Chunk>;
+
+/// Position within a chunk.
+pub type LocalCoordinate = SpaceCoordinate<Local>;
+
+impl<S: Space, C> SpaceCoordinate<S, C> {
+    /// Tags `coordinate` with the space `S`.
+    pub fn new(coordinate: C) -> Self {
+        Self {
+            coordinate,
+            space: PhantomData,
+        }
+    }
+
+    /// Removes the tag, returning the plain coordinate.
+    pub fn into_inner(self) -> C {
+        self.coordinate
+    }
+
+    /// Returns a reference to the plain coordinate.
+    pub fn as_inner(&self) -> &C {
+        &self.coordinate
+    }
+}
+
+// Implemented by hand, because deriving would also require them from the marker `S`.
+
+impl<S: Space, C: Clone> Clone for SpaceCoordinate<S, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.coordinate.clone())
+    }
+}
+
+impl<S: Space, C: Copy> Copy for SpaceCoordinate<S, C> {}
+
+impl<S: Space, C: PartialEq> PartialEq for SpaceCoordinate<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coordinate == other.coordinate
+    }
+}
+
+impl<S: Space, C: Eq> Eq for SpaceCoordinate<S, C> {}
+
+impl<S: Space, C: Hash> Hash for SpaceCoordinate<S, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coordinate.hash(state);
+    }
+}
+
+impl<S: Space, C: PartialOrd> PartialOrd for SpaceCoordinate<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.coordinate.partial_cmp(&other.coordinate)
+    }
+}
+
+impl<S: Space, C: Ord> Ord for SpaceCoordinate<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.coordinate.cmp(&other.coordinate)
+    }
+}
+
+/// Returns the chunk size to calculate with.
+///
+/// Every size beyond `2^31` splits the `Coordinate`s into the same two chunks, the negative ones
+/// and the rest, so larger sizes are treated as `2^31`. This keeps every local position and
+/// chunk origin representable.
+pub(crate) fn chunk_size(chunk_size: NonZero<u32>) -> i64 {
+    i64::from(chunk_size.get()).min(i64::from(CoordinateType::MAX) + 1)
+}
+
+/// Converts the result of a chunk calculation back, which always lies within the field's range.
+fn field(n: i64) -> CoordinateType {
+    CoordinateType::try_from(n).expect("chunk calculations stay within the coordinate range")
+}
+
+impl WorldCoordinate {
+    /// Returns the position of the chunk containing this position.
+    pub fn to_chunk(&self, chunk_size: NonZero<u32>) -> ChunkCoordinate {
+        let size = self::chunk_size(chunk_size);
+        let index = |n: CoordinateType| field(i64::from(n).div_euclid(size));
+        SpaceCoordinate::new(Coordinate::new(
+            index(self.coordinate.x),
+            index(self.coordinate.y),
+            index(self.coordinate.z),
+        ))
+    }
+
+    /// Returns this position relative to the origin of the chunk containing it.
+    pub fn to_local(&self, chunk_size: NonZero<u32>) -> LocalCoordinate {
+        let size = self::chunk_size(chunk_size);
+        let local = |n: CoordinateType| field(i64::from(n).rem_euclid(size));
+        SpaceCoordinate::new(Coordinate::new(
+            local(self.coordinate.x),
+            local(self.coordinate.y),
+            local(self.coordinate.z),
+        ))
+    }
+
+    /// Splits this position into the chunk containing it and the position within that chunk.
+    pub fn split(&self, chunk_size: NonZero<u32>) -> (ChunkCoordinate, LocalCoordinate) {
+        (self.to_chunk(chunk_size), self.to_local(chunk_size))
+    }
+}
+
+impl ChunkCoordinate {
+    /// Returns the position of the chunk's origin.
+    ///
+    /// Returns `None` if the origin can't be represented by a `Coordinate`.
+    pub fn origin(&self, chunk_size: NonZero<u32>) -> Option<WorldCoordinate> {
+        let size = self::chunk_size(chunk_size);
+        let origin = |n: CoordinateType| CoordinateType::try_from(i64::from(n) * size).ok();
+        Some(SpaceCoordinate::new(Coordinate::new(
+            origin(self.coordinate.x)?,
+            origin(self.coordinate.y)?,
+            origin(self.coordinate.z)?,
+        )))
+    }
+
+    /// Returns the position of `local` within this chunk.
+    ///
+    /// Returns `None` if the position can't be represented by a `Coordinate`.
+    pub fn to_world(
+        &self,
+        local: &LocalCoordinate,
+        chunk_size: NonZero<u32>,
+    ) -> Option<WorldCoordinate> {
+        let origin = self.origin(chunk_size)?;
+        Some(SpaceCoordinate::new(Coordinate::new(
+            origin.coordinate.x.checked_add(local.coordinate.x)?,
+            origin.coordinate.y.checked_add(local.coordinate.y)?,
+            origin.coordinate.z.checked_add(local.coordinate.z)?,
+        )))
+    }
+}
+
+impl<S: Space, C: Display> Display for SpaceCoordinate<S, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.coordinate.fmt(f)
+    }
+}
+
+impl<S: Space, C: Add<Output = C>> Add for SpaceCoordinate<S, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.coordinate + rhs.coordinate)
+    }
+}
+
+impl<S: Space, C: AddAssign> AddAssign for SpaceCoordinate<S, C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.coordinate += rhs.coordinate;
+    }
+}
+
+impl<S: Space, C: Sub<Output = C>> Sub for SpaceCoordinate<S, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.coordinate - rhs.coordinate)
+    }
+}
+
+impl<S: Space, C: SubAssign> SubAssign for SpaceCoordinate<S, C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.coordinate -= rhs.coordinate;
+    }
+}
+
+impl<S: Space, C: Neg<Output = C>> Neg for SpaceCoordinate<S, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk_size() -> NonZero<u32> {
+        NonZero::new(16).unwrap()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = WorldCoordinate::new(Coordinate::new(1, 2, 3));
+        let b = WorldCoordinate::new(Coordinate::new(4, 5, 6));
+        assert_eq!((a + b).into_inner(), Coordinate::new(5, 7, 9));
+        assert_eq!((b - a).into_inner(), Coordinate::splat(3));
+    }
+
+    #[test]
+    fn map_keys() {
+        let a = ChunkCoordinate::new(Coordinate::new(0, 0, 1));
+        let b = ChunkCoordinate::new(Coordinate::new(1, 0, 0));
+        let chunks: alloc::collections::BTreeSet<_> = [a, b, a].into_iter().collect();
+        assert_eq!(chunks.into_iter().collect::<alloc::vec::Vec<_>>(), [b, a]);
+    }
+
+    #[test]
+    fn split_negative() {
+        let world = WorldCoordinate::new(Coordinate::new(-16, -17, -1));
+        let (chunk, local) = world.split(chunk_size());
+        assert_eq!(chunk.as_inner(), &Coordinate::new(-1, -2, -1));
+        assert_eq!(local.into_inner(), Coordinate::new(0, 15, 15));
+    }
+
+    #[test]
+    fn round_trip_at_limits() {
+        for coordinate in [Coordinate::MIN, Coordinate::MAX] {
+            let world = WorldCoordinate::new(coordinate);
+            let (chunk, local) = world.split(chunk_size());
+            assert_eq!(chunk.to_world(&local, chunk_size()), Some(world));
+        }
+    }
+
+    #[test]
+    fn origin_overflow() {
+        let chunk = ChunkCoordinate::new(Coordinate::MAX);
+        assert_eq!(chunk.origin(chunk_size()), None);
+    }
+
+    #[test]
+    fn large_chunks() {
+        let world = WorldCoordinate::new(Coordinate::new(-1, 1_000, 70_000));
+        let (chunk, local) = world.split(NonZero::new(1_000).unwrap());
+        assert_eq!(chunk.into_inner(), Coordinate::new(-1, 1, 70));
+        assert_eq!(local.into_inner(), Coordinate::new(999, 0, 0));
+
+        let size = NonZero::new(u32::MAX).unwrap();
+        for coordinate in [Coordinate::MIN, Coordinate::splat(-1), Coordinate::MAX] {
+            let world = WorldCoordinate::new(coordinate);
+            let (chunk, local) = world.split(size);
+            assert_eq!(chunk.to_world(&local, size), Some(world));
+        }
+        assert_eq!(
+            WorldCoordinate::new(Coordinate::new(-1, 0, 5))
+                .to_chunk(size)
+                .into_inner(),
+            Coordinate::new(-1, 0, 0)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use core::{fmt::Debug, num::NonZero};
+
+use proptest::prelude::*;
+
+use crate::{prelude::*, region::NonZeroRegionSize};
+
+/// Generates any coordinate of type `C`.
+///
+/// # Examples
+///
+/// ```
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::strategies::any_coordinate;
+/// use proptest::proptest;
+///
+/// proptest!(|(coordinate in any_coordinate::<UnsignedCoordinate<u8>>())| {
+///     assert!(coordinate.x <= u8::MAX);
+/// });
+/// ```
+pub fn any_coordinate<C>() -> impl Strategy<Value = C>
+where
+    C: CoordinateTrait<Type = C> + Debug,
+    C::FieldType: Arbitrary,
+{
+    (
+        any::<C::FieldType>(),
+        any::<C::FieldType>(),
+        any::<C::FieldType>(),
+    )
+        .prop_map(|(x, y, z)| C::new(x, y, z))
+}
+
+/// Generates `Coordinate`s with every axis at most `distance` away from `CoordinateType::MIN`.
+pub fn coordinate_near_min(distance: CoordinateType) -> impl Strategy<Value = Coordinate> {
+    let axis = CoordinateType::MIN..=CoordinateType::MIN.saturating_add(distance);
+    (axis.clone(), axis.clone(), axis).prop_map(|(x, y, z)| Coordinate::new(x, y, z))
+}
+
+/// Generates `Coordinate`s with every axis at most `distance` away from `CoordinateType::MAX`.
+pub fn coordinate_near_max(distance: CoordinateType) -> impl Strategy<Value = Coordinate> {
+    let axis = CoordinateType::MAX.saturating_sub(distance)..=CoordinateType::MAX;
+    (axis.clone(), axis.clone(), axis).prop_map(|(x, y, z)| Coordinate::new(x, y, z))
+}
+
+/// Generates `Coordinate`s within `region`.
+///
+/// Generates no values if `region` is empty, so every test case using it is rejected.
+pub fn coordinate_within(region: Region) -> impl Strategy<Value = Coordinate> {
+    let volume = region.volume_usize().unwrap_or(usize::MAX);
+    (0..volume.max(1)).prop_filter_map("region is empty", move |index| region.coordinate_at(index))
+}
+
+/// Generates `Region`s of up to `max_size` that can be fully iterated without overflowing.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// #     test().unwrap();
+/// # }
+/// # fn test() -> Option<()> {
+/// use std::num::NonZero;
+/// use argentum_game_coordinate_system::prelude::*;
+/// use argentum_game_coordinate_system::strategies::region;
+/// use proptest::proptest;
+///
+/// proptest!(|(region in region(NonZero::new(4)?))| {
+///     assert!(region.size().unwrap() <= 4);
+/// });
+/// # Some(())
+/// # }
+/// ```
+pub fn region(max_size: NonZeroRegionSize<Coordinate>) -> impl Strategy<Value = Region> {
+    (1..=max_size.get()).prop_flat_map(|size| {
+        let axis = CoordinateType::MIN..=CoordinateType::MAX.saturating_sub_unsigned(size - 1);
+        (axis.clone(), axis.clone(), axis).prop_map(move |(x, y, z)| {
+            let size = NonZero::new(size).expect("sizes start at 1");
+            Region::new(Coordinate::new(x, y, z), size)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_empty() {
+        let mut runner = proptest::test_runner::TestRunner::default();
+        assert!(coordinate_within(Region::EMPTY)
+            .new_tree(&mut runner)
+            .is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn near_max(coordinate in coordinate_near_max(2)) {
+            prop_assert!(coordinate.x >= CoordinateType::MAX - 2);
+            prop_assert!(coordinate.y >= CoordinateType::MAX - 2);
+            prop_assert!(coordinate.z >= CoordinateType::MAX - 2);
+        }
+
+        #[test]
+        fn regions_iterate(region in region(NonZero::new(4).unwrap())) {
+            prop_assert_eq!(Some(region.clone().count() as u128), region.volume());
+        }
+
+        #[test]
+        fn within(coordinate in coordinate_within(Region::new(Coordinate::splat(-3), NonZero::new(6).unwrap()))) {
+            prop_assert!(coordinate.x >= -3 && coordinate.x < 3);
+        }
+    }
+}